@@ -0,0 +1,400 @@
+#![deny(missing_docs)]
+
+//! This crate provides the `#[bitfield]` attribute macro, an alternative front-end for the
+//! `bitfield!` macro of [const-bitfield](https://crates.io/crates/const-bitfield).
+//!
+//! Instead of the field list of `bitfield!`, bitfields are declared as a regular struct with named
+//! fields, which keeps them readable for rustfmt and IDEs and allows pointing errors at the
+//! offending field. The struct is expanded into a `bitfield!` invocation, so both front-ends
+//! generate the exact same API and can be mixed freely while migrating.
+//!
+//! As the expansion refers to `::const_bitfield`, the `const-bitfield` crate must be a dependency
+//! as well, along with the feature flags it requires.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Error, Expr, ExprLit, ExprRange, Fields, Ident, ItemStruct, Lit, RangeLimits,
+    Result, Token, Type, Visibility,
+};
+
+/// Generate a bitfield-like struct from a struct with named fields.
+///
+/// The attribute takes the storage type of the bitfield, optionally followed by `debug` to
+/// implement `core::fmt::Debug` like `impl Debug;` does. Every field must declare its position
+/// using either `#[bits(msb..=lsb)]` or `#[bit(n)]`, listing the most significant bit first like
+/// `bitfield!` does. The getter is named after the field and the setter is prefixed with `set_`,
+/// both using the visibility of the field. Other struct attributes, such as derives or
+/// `#[bit_order(msb0)]`, must follow `#[bitfield]` and are passed on to `bitfield!`.
+///
+/// Fields of type `bool` or any primitive integer are accessed as-is. Fields of any other type are
+/// converted using `From`, or `TryFrom` when `try` is given. The raw type of such fields defaults
+/// to `bool` for single bits and to the smallest unsigned integer fitting a literal bit range, but
+/// can be overridden using `raw = T`. The following options may follow the bit position:
+///
+/// - `raw = T` to override the raw type of a converted or nested field
+/// - `try` to convert using `TryFrom` instead of `From`
+/// - `nested` to store another bitfield, given its storage type is the raw type
+/// - `default = value` to declare a default value
+/// - `stride = n` to shift every element of an array field by `n` bits
+/// - `overlap` to allow sharing bits with other overlapping fields
+/// - `read_only` or `write_only` to only generate a getter or a setter
+///
+/// Fields of an array type such as `[u8; 4]` are declared as field arrays, with the bit position
+/// describing the first element. Fields marked with `#[reserved(0)]` or `#[reserved(1)]` must be of
+/// type `()` and declare reserved bits instead, using the field name only for documentation.
+///
+/// # Example
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield_macros::bitfield;
+///
+/// #[bitfield(u16, debug)]
+/// #[derive(Copy, Clone)]
+/// pub struct BitField {
+///     #[bits(7..=0, default = 0x2A)]
+///     pub field1: u8,
+///     #[bit(8)]
+///     pub field2: bool,
+///     #[bits(11..=9, read_only)]
+///     pub field3: u8,
+///     #[bits(15..=12)]
+///     #[reserved(0)]
+///     reserved: (),
+/// }
+///
+/// const VALUE: BitField = BitField::new().with_field2(true);
+/// assert_eq!(VALUE.0, 0x12A);
+/// assert_eq!(VALUE.field3(), 0);
+/// ```
+#[proc_macro_attribute]
+pub fn bitfield(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let arguments = parse_macro_input!(attribute as Arguments);
+    let item = parse_macro_input!(item as ItemStruct);
+
+    match expand(arguments, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Arguments of the `#[bitfield]` attribute.
+struct Arguments {
+    storage: Type,
+    debug: bool,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let storage = input.parse()?;
+        let mut debug = false;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option: Ident = input.parse()?;
+            match option.to_string().as_str() {
+                "debug" => debug = true,
+                _ => return Err(Error::new(option.span(), "unknown bitfield option, expected `debug`")),
+            }
+        }
+
+        Ok(Arguments { storage, debug })
+    }
+}
+
+/// Bit position of a field, as given by `#[bit]` or `#[bits]`.
+enum Position {
+    Bit(Expr),
+    Bits(Expr, Expr),
+}
+
+/// Options following the bit position of a field.
+#[derive(Default)]
+struct Options {
+    raw: Option<Type>,
+    fallible: bool,
+    nested: bool,
+    default: Option<Expr>,
+    stride: Option<Expr>,
+    overlap: bool,
+    read_only: bool,
+    write_only: bool,
+}
+
+/// Bit position of a field along with its options.
+struct Layout {
+    position: Position,
+    options: Options,
+}
+
+impl Layout {
+    fn parse_bit(input: ParseStream) -> Result<Self> {
+        let bit = input.parse()?;
+        let options = Options::parse(input)?;
+        Ok(Layout { position: Position::Bit(bit), options })
+    }
+
+    fn parse_bits(input: ParseStream) -> Result<Self> {
+        let range: ExprRange = input.parse()?;
+        let span = range.span();
+        let (Some(msb), RangeLimits::Closed(_), Some(lsb)) = (range.start, range.limits, range.end) else {
+            return Err(Error::new(span, "expected an inclusive bit range such as `7..=0`"));
+        };
+        let options = Options::parse(input)?;
+        Ok(Layout { position: Position::Bits(*msb, *lsb), options })
+    }
+}
+
+impl Options {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut options = Options::default();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option = input.call(Ident::parse_any)?;
+            match option.to_string().as_str() {
+                "raw" => {
+                    input.parse::<Token![=]>()?;
+                    options.raw = Some(input.parse()?);
+                }
+                "default" => {
+                    input.parse::<Token![=]>()?;
+                    options.default = Some(input.parse()?);
+                }
+                "stride" => {
+                    input.parse::<Token![=]>()?;
+                    options.stride = Some(input.parse()?);
+                }
+                "try" if options.nested => {
+                    return Err(Error::new(option.span(), "nested fields can not be converted using `try`"))
+                }
+                "nested" if options.fallible => {
+                    return Err(Error::new(option.span(), "nested fields can not be converted using `try`"))
+                }
+                "read_only" if options.write_only => {
+                    return Err(Error::new(option.span(), "field can not be both `read_only` and `write_only`"))
+                }
+                "write_only" if options.read_only => {
+                    return Err(Error::new(option.span(), "field can not be both `read_only` and `write_only`"))
+                }
+                "try" => options.fallible = true,
+                "nested" => options.nested = true,
+                "overlap" => options.overlap = true,
+                "read_only" => options.read_only = true,
+                "write_only" => options.write_only = true,
+                _ => {
+                    return Err(Error::new(
+                        option.span(),
+                        "unknown field option, expected one of `raw`, `try`, `nested`, `default`, `stride`, \
+                         `overlap`, `read_only` or `write_only`",
+                    ))
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn expand(arguments: Arguments, item: ItemStruct) -> Result<TokenStream2> {
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return Err(Error::new(item.generics.span(), "bitfields can not be generic"));
+    }
+    let Fields::Named(named) = &item.fields else {
+        return Err(Error::new(item.fields.span(), "bitfields must be declared with named fields"));
+    };
+
+    let mut errors: Option<Error> = None;
+    let mut fields = Vec::new();
+    for field in &named.named {
+        match expand_field(field.attrs.clone(), &field.vis, field.ident.as_ref().unwrap(), &field.ty) {
+            Ok(tokens) => fields.push(tokens),
+            Err(error) => match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            },
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let attributes = &item.attrs;
+    let visibility = &item.vis;
+    let name = &item.ident;
+    let storage = &arguments.storage;
+    let debug = arguments.debug.then(|| quote!(impl Debug;));
+
+    Ok(quote! {
+        ::const_bitfield::bitfield! {
+            #(#attributes)*
+            #visibility struct #name(#storage);
+            #debug
+            #(#fields)*
+        }
+    })
+}
+
+fn expand_field(attributes: Vec<Attribute>, visibility: &Visibility, name: &Ident, ty: &Type) -> Result<TokenStream2> {
+    let mut layout = None;
+    let mut reserved = None;
+    let mut passed = Vec::new();
+
+    for attribute in attributes {
+        let path = attribute.path();
+        if path.is_ident("bit") || path.is_ident("bits") {
+            if layout.is_some() {
+                return Err(Error::new(attribute.span(), "bit position of field is declared more than once"));
+            }
+            layout = Some(if path.is_ident("bit") {
+                attribute.parse_args_with(Layout::parse_bit)?
+            } else {
+                attribute.parse_args_with(Layout::parse_bits)?
+            });
+        } else if path.is_ident("reserved") {
+            let value: ExprLit = attribute.parse_args()?;
+            match &value.lit {
+                Lit::Int(int) if int.base10_digits() == "0" || int.base10_digits() == "1" => reserved = Some(value),
+                _ => return Err(Error::new(value.span(), "reserved bits must be either `0` or `1`")),
+            }
+        } else {
+            passed.push(attribute);
+        }
+    }
+
+    let Some(Layout { position, options }) = layout else {
+        return Err(Error::new(name.span(), "missing bit position, add `#[bits(msb..=lsb)]` or `#[bit(n)]`"));
+    };
+    let (msb, lsb) = match &position {
+        Position::Bit(bit) => (single(bit), None),
+        Position::Bits(msb, lsb) => (single(msb), Some(single(lsb))),
+    };
+    let bits = match lsb {
+        Some(lsb) => quote!(#msb, #lsb),
+        None => quote!(#msb),
+    };
+
+    // Reserved bits only declare their position and value
+    if let Some(value) = reserved {
+        if !matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty()) {
+            return Err(Error::new(ty.span(), "reserved bits must be declared with type `()`"));
+        }
+        return Ok(quote!(reserved #value: #bits;));
+    }
+
+    // Array fields declare the element type and count within their type
+    let (element, array) = match ty {
+        Type::Array(array) => {
+            let count = &array.len;
+            let stride = options.stride.as_ref().map(|stride| {
+                let stride = single(stride);
+                quote!(stride #stride)
+            });
+            (&*array.elem, Some((quote!([#count]), stride)))
+        }
+        _ => {
+            if let Some(stride) = &options.stride {
+                return Err(Error::new(stride.span(), "`stride` is only supported for array fields"));
+            }
+            (ty, None)
+        }
+    };
+
+    // Determine raw type and conversion of the field
+    let (raw, conversion) = if is_primitive(element) && !options.nested {
+        if let Some(raw) = &options.raw {
+            return Err(Error::new(raw.span(), "`raw` is only supported for converted or nested fields"));
+        }
+        if options.fallible {
+            return Err(Error::new(name.span(), "`try` is only supported for converted fields"));
+        }
+        (element.to_token_stream(), None)
+    } else {
+        let raw = match &options.raw {
+            Some(raw) => raw.to_token_stream(),
+            None => infer_raw(&position).ok_or_else(|| {
+                Error::new(name.span(), "can not infer raw type of field, specify it using `raw = T`")
+            })?,
+        };
+        let conversion = match (options.nested, options.fallible) {
+            (true, _) => quote!(nested #element,),
+            (false, true) => quote!(try_from try_into #element,),
+            (false, false) => quote!(from into #element,),
+        };
+        (raw, Some(conversion))
+    };
+
+    let getter = if options.write_only { quote!(_) } else { name.to_token_stream() };
+    let setter = if options.read_only { quote!(_) } else { format_ident!("set_{}", name.unraw()).to_token_stream() };
+    let overlap = options.overlap.then(|| quote!(overlap));
+    let default = options.default.map(|default| quote!(= #default));
+    let (count, stride) = array.unzip();
+
+    Ok(quote! {
+        #(#passed)* #visibility #overlap #raw, #conversion #getter, #setter: #count #bits #stride #default;
+    })
+}
+
+/// Wrap a bit position in parentheses unless it already is a single token, as required by
+/// `bitfield!` for fields with default values.
+fn single(expr: &Expr) -> TokenStream2 {
+    match expr {
+        Expr::Lit(_) => expr.to_token_stream(),
+        Expr::Path(path) if path.path.get_ident().is_some() => expr.to_token_stream(),
+        _ => quote!((#expr)),
+    }
+}
+
+/// Check if the type is `bool` or a primitive integer, which are accessed without conversion.
+fn is_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.get_ident().is_some_and(|ident| PRIMITIVES.contains(&ident.to_string().as_str()))
+        }
+        _ => false,
+    }
+}
+
+/// Infer the raw type of a converted field, being `bool` for single bits and the smallest unsigned
+/// integer fitting the width of literal bit ranges.
+fn infer_raw(position: &Position) -> Option<TokenStream2> {
+    let (msb, lsb) = match position {
+        Position::Bit(_) => return Some(quote!(bool)),
+        Position::Bits(msb, lsb) => (literal(msb)?, literal(lsb)?),
+    };
+
+    let raw = match msb.abs_diff(lsb) + 1 {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    };
+    Some(Ident::new(raw, Span::call_site()).into_token_stream())
+}
+
+/// Get the value of an integer literal, if the expression is one.
+fn literal(expr: &Expr) -> Option<u128> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(int), .. }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,230 @@
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield_macros::bitfield;
+#[cfg(not(feature = "stable"))]
+use const_enum::ConstEnum;
+
+const KERNEL_CODE64: u64 = 0x00AF9B000000FFFF;
+const KERNEL_DATA: u64 = 0x00CF93000000FFFF;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "stable"), derive(ConstEnum))]
+#[repr(u8)]
+pub enum SegmentType {
+    DataReadOnly = 0b000,
+    DataReadWrite = 0b001,
+    DataReadOnlyDown = 0b010,
+    DataReadWriteDown = 0b011,
+    CodeExecOnly = 0b100,
+    CodeExecRead = 0b101,
+    CodeExecOnlyConforming = 0b110,
+    CodeExecReadConforming = 0b111,
+}
+
+#[cfg(feature = "stable")]
+impl From<SegmentType> for u8 {
+    fn from(value: SegmentType) -> Self {
+        value as Self
+    }
+}
+
+#[cfg(feature = "stable")]
+impl From<u8> for SegmentType {
+    fn from(value: u8) -> Self {
+        match value {
+            0b000 => SegmentType::DataReadOnly,
+            0b001 => SegmentType::DataReadWrite,
+            0b010 => SegmentType::DataReadOnlyDown,
+            0b011 => SegmentType::DataReadWriteDown,
+            0b100 => SegmentType::CodeExecOnly,
+            0b101 => SegmentType::CodeExecRead,
+            0b110 => SegmentType::CodeExecOnlyConforming,
+            0b111 => SegmentType::CodeExecReadConforming,
+            _ => panic!("invalid value provided"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InvalidMode(u8);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    User,
+    Supervisor,
+}
+
+// const trait implementation on Rust nightly, regular trait implementation with `stable` feature
+#[cfg(not(feature = "stable"))]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl const $($impl)* };
+}
+
+#[cfg(feature = "stable")]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl $($impl)* };
+}
+
+impl_convert! {TryFrom<u8> for Mode {
+    type Error = InvalidMode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b10 => Ok(Mode::User),
+            0b11 => Ok(Mode::Supervisor),
+            _ => Err(InvalidMode(value)),
+        }
+    }
+}}
+
+impl_convert! {TryFrom<Mode> for u8 {
+    type Error = InvalidMode;
+
+    fn try_from(value: Mode) -> Result<Self, Self::Error> {
+        match value {
+            Mode::User => Ok(0b10),
+            Mode::Supervisor => Ok(0b11),
+        }
+    }
+}}
+
+#[bitfield(u64, debug)]
+#[derive(Copy, Clone)]
+pub struct Descriptor {
+    #[bits(15..=0)]
+    pub limit_0_15: u32,
+    #[bits(31..=16)]
+    pub base_0_15: u32,
+    #[bits(39..=32)]
+    pub base_16_23: u8,
+    #[bit(40)]
+    pub accessed: bool,
+    #[bits(43..=41)]
+    pub segment_type: SegmentType,
+    #[bit(44, default = true)]
+    pub user_segment: bool,
+    #[bits(46..=45)]
+    pub privilege_level: u8,
+    #[bit(47, default = true)]
+    pub present: bool,
+    #[bits(51..=48)]
+    pub limit_16_19: u8,
+    #[bit(52)]
+    #[reserved(0)]
+    reserved: (),
+    #[bit(53)]
+    pub long_mode: bool,
+    #[bit(54)]
+    pub size_flag: bool,
+    #[bit(55)]
+    pub granularity: bool,
+    #[bits(63..=56)]
+    pub base_24_31: u8,
+}
+
+const_bitfield::bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Permissions(u8);
+    bool, writable, set_writable: 0;
+    bool, user, set_user: 1;
+}
+
+#[bitfield(u32)]
+#[bit_order(msb0)]
+#[derive(Copy, Clone)]
+pub struct Control {
+    /// Mode of the controller.
+    #[bits(0..=1, try)]
+    pub mode: Mode,
+    #[bits(2..=3, nested)]
+    pub permissions: Permissions,
+    #[bits(4..=7, stride = 8)]
+    pub lanes: [u8; 3],
+    #[bits(24..=27, overlap, write_only)]
+    pub low: u8,
+    #[bits(24..=27, overlap, read_only)]
+    pub low_raw: u8,
+    #[bits(28..=31)]
+    pub level: u8,
+}
+
+#[test]
+pub fn test_parse_descriptor() {
+    let descriptor = Descriptor(KERNEL_CODE64);
+
+    assert_eq!(descriptor.limit_0_15(), 0xFFFF);
+    assert_eq!(descriptor.limit_16_19(), 0xF);
+    assert!(descriptor.accessed());
+    assert_eq!(descriptor.segment_type(), SegmentType::CodeExecRead);
+    assert!(descriptor.user_segment());
+    assert_eq!(descriptor.privilege_level(), 0);
+    assert!(descriptor.present());
+    assert!(descriptor.long_mode());
+    assert!(!descriptor.size_flag());
+    assert!(descriptor.granularity());
+    assert_eq!(descriptor.base_24_31(), 0);
+}
+
+#[test]
+pub fn test_build_descriptor() {
+    let descriptor = Descriptor(0)
+        .with_limit_0_15(0xFFFF)
+        .with_limit_16_19(0xF)
+        .with_accessed(true)
+        .with_segment_type(SegmentType::CodeExecRead)
+        .with_user_segment(true)
+        .with_present(true)
+        .with_long_mode(true)
+        .with_granularity(true);
+    assert_eq!(descriptor.0, KERNEL_CODE64);
+
+    const DEFAULT: Descriptor = Descriptor::new();
+    let mut descriptor = DEFAULT;
+    descriptor
+        .set_segment_type(SegmentType::DataReadWrite)
+        .set_limit_0_15(0xFFFF)
+        .set_limit_16_19(0xF)
+        .set_accessed(true)
+        .set_size_flag(true)
+        .set_granularity(true);
+    assert_eq!(descriptor.0, KERNEL_DATA);
+}
+
+#[test]
+pub fn test_descriptor_api() {
+    assert_eq!(Descriptor::SEGMENT_TYPE_MASK, 0b111 << 41);
+    assert_eq!(Descriptor::PRESENT_SHIFT, 47);
+    assert_eq!(Descriptor::RESERVED_MASK, 1 << 52);
+    assert!(!Descriptor(KERNEL_CODE64 | 1 << 52).is_valid());
+
+    let mut descriptor = Descriptor(KERNEL_CODE64);
+    descriptor.set_present(false).reset_present();
+    assert!(descriptor.present());
+    assert!(descriptor.try_set_privilege_level(4).is_err());
+    assert_eq!(
+        format!("{:?}", Descriptor(KERNEL_DATA)),
+        "Descriptor { raw: 0x00cf93000000ffff, limit_0_15: 65535, base_0_15: 0, base_16_23: 0, accessed: true, \
+         segment_type: DataReadWrite, user_segment: true, privilege_level: 0, present: true, limit_16_19: 15, \
+         long_mode: false, size_flag: true, granularity: true, base_24_31: 0 }"
+    );
+}
+
+#[test]
+pub fn test_control() {
+    let mut control = Control(0);
+    control.set_mode(Mode::Supervisor).unwrap();
+    control.permissions_mut().set_user(true);
+    control.set_lanes(2, 0xA).set_low(0x5).set_level(0x3);
+
+    assert_eq!(control.0, 0xE000_0A53);
+    assert_eq!(control.mode(), Ok(Mode::Supervisor));
+    assert_eq!(control.permissions(), Permissions(0b10));
+    assert_eq!(control.lanes(1), 0);
+    assert_eq!(control.lanes(2), 0xA);
+    assert_eq!(control.low_raw(), 0x5);
+    assert_eq!(Control::LANES_COUNT, 3);
+
+    assert_eq!(Control(0).mode(), Err(InvalidMode(0)));
+}
@@ -0,0 +1,9 @@
+// trybuild only forwards features to this crate, which can not enable the `stable` feature of the
+// const-bitfield dev-dependency used by the test cases
+#[cfg(not(feature = "stable"))]
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/*-pass-*.rs");
+    t.compile_fail("tests/ui/*-fail-*.rs");
+}
@@ -0,0 +1,40 @@
+#![feature(const_mut_refs)]
+#![feature(const_trait_impl)]
+
+use const_bitfield_macros::bitfield;
+
+#[bitfield(u8)]
+struct Single {
+    #[bit(0)]
+    bit_0: bool,
+    #[bit(1, read_only)]
+    bit_1: bool,
+    #[bit(2, write_only)]
+    bit_2: bool,
+}
+
+#[bitfield(u16)]
+pub struct Range {
+    #[bits(3..=0)]
+    pub bits_3_0: u8,
+    #[bits(15..=4)]
+    pub(crate) bits_15_4: i16,
+}
+
+#[bitfield([u32; 2])]
+struct Wide {
+    #[bits(47..=16)]
+    bits_47_16: u32,
+}
+
+const OFFSET: usize = 4;
+
+#[bitfield(u16)]
+struct Expressions {
+    #[bits(OFFSET + 3..=OFFSET, default = 0x5)]
+    nibble: u8,
+    #[bit(OFFSET * 2)]
+    flag: bool,
+}
+
+fn main() {}
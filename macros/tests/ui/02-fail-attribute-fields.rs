@@ -0,0 +1,38 @@
+#![feature(const_mut_refs)]
+#![feature(const_trait_impl)]
+
+use const_bitfield_macros::bitfield;
+
+pub struct Custom(u8);
+
+const OFFSET: usize = 14;
+
+#[bitfield(u16)]
+struct Invalid {
+    missing: bool,
+    #[bit(0)]
+    #[bit(1)]
+    twice: bool,
+    #[bits(3..5)]
+    exclusive: u8,
+    #[bits(7..=4, raw = u8)]
+    raw_primitive: u8,
+    #[bits(8..=8, stride = 2)]
+    stride_scalar: bool,
+    #[bit(9, volatile)]
+    unknown_option: bool,
+    #[bits(11..=10, read_only, write_only)]
+    no_accessor: u8,
+    #[bits(OFFSET..=12)]
+    inferred: Custom,
+    #[bits(13..=12, nested, try)]
+    nested_try: Custom,
+    #[bit(15)]
+    #[reserved(2)]
+    reserved_value: (),
+    #[bit(15)]
+    #[reserved(0)]
+    reserved_type: bool,
+}
+
+fn main() {}
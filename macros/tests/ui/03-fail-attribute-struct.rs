@@ -0,0 +1,21 @@
+#![feature(const_mut_refs)]
+#![feature(const_trait_impl)]
+
+use const_bitfield_macros::bitfield;
+
+#[bitfield(u8)]
+struct Tuple(u8);
+
+#[bitfield(u8)]
+struct Generic<T> {
+    #[bit(0)]
+    flag: T,
+}
+
+#[bitfield(u8, display)]
+struct UnknownOption {
+    #[bit(0)]
+    flag: bool,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! Placeholder for the defmt directive of the `bitfield!` macro when the `defmt` feature is disabled.
+
+/// Reject the defmt directive, as the `defmt` feature is required for implementing it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_defmt {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`impl ",
+            ::core::stringify!($directive),
+            ";` requires the `defmt` feature of const-bitfield",
+        ));
+    };
+}
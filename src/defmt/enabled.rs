@@ -0,0 +1,37 @@
+//! Support for the `impl Format;` directive of the `bitfield!` macro.
+
+use crate::{BitRange, DebugArray, DebugHex};
+
+pub use ::defmt::{write, Format, Formatter};
+
+impl<T: BitRange<u8>> Format for DebugHex<'_, T> {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "0x");
+        for index in (0..core::mem::size_of::<T>()).rev() {
+            let byte: u8 = self.0.bits(index * 8 + 7, index * 8);
+            ::defmt::write!(f, "{=u8:02x}", byte);
+        }
+    }
+}
+
+impl<T: Format, F: Fn(usize) -> T> Format for DebugArray<F> {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "[");
+        for index in 0..self.0 {
+            if index != 0 {
+                ::defmt::write!(f, ", ");
+            }
+            ::defmt::write!(f, "{}", (self.1)(index));
+        }
+        ::defmt::write!(f, "]");
+    }
+}
+
+/// Pass through the implementation generated by the `impl Format;` directive.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_defmt {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
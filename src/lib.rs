@@ -1,30 +1,96 @@
 #![no_std]
 #![deny(missing_docs)]
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
 //! This crate provides macros to generate bitfield-like structs with const support.
 //!
 //! Due to offering const support, this library requires the usage of Rust nightly.
 //! Additionally, you must add the following feature flags to your crate root:
 //!
-//! ```rust
+#![cfg_attr(not(feature = "stable"), doc = "```rust")]
+#![cfg_attr(feature = "stable", doc = "```ignore")]
 //! #![feature(const_convert)]      // optional, when using from/into conversion
 //! #![feature(const_mut_refs)]     // always required
+//! #![feature(const_ops)]          // optional, when using `impl BitOps;`
 //! #![feature(const_trait_impl)]   // always required
 //! ```
 //!
 //! This is required as some required features are currently gated behind these flags.
 //! Further documentation about usage can be found in the individual macros.
+//!
+//! Alternatively, the `stable` feature of this crate can be enabled to use it on Rust stable,
+//! without any of the feature flags above. Getters, setters and `with_` builders are then
+//! generated as plain const fns operating on the backing storage directly. As const traits are
+//! not available on Rust stable, fields using `from`, `into` or `try` conversions get regular
+//! non-const accessors and can not be used within default values, while `impl BitOps;` generates
+//! regular non-const operator implementations.
+
+#[doc(hidden)]
+pub use paste;
+
+#[doc(hidden)]
+#[cfg_attr(not(feature = "stable"), path = "ops/nightly.rs")]
+#[cfg_attr(feature = "stable", path = "ops/stable.rs")]
+pub mod ops;
+
+#[doc(hidden)]
+#[cfg_attr(feature = "serde", path = "serde/enabled.rs")]
+#[cfg_attr(not(feature = "serde"), path = "serde/disabled.rs")]
+pub mod serde;
 
-/// This macro defines a new bitfield-like `struct` backed by a single uint-like type.
+#[doc(hidden)]
+#[cfg_attr(feature = "defmt", path = "defmt/enabled.rs")]
+#[cfg_attr(not(feature = "defmt"), path = "defmt/disabled.rs")]
+pub mod defmt;
+
+#[doc(hidden)]
+#[cfg_attr(feature = "ufmt", path = "ufmt/enabled.rs")]
+#[cfg_attr(not(feature = "ufmt"), path = "ufmt/disabled.rs")]
+pub mod ufmt;
+
+/// This macro defines a new bitfield-like `struct` backed by a single uint-like type,
+/// or by an array of uint-like types such as `[u64; 4]` for layouts wider than 128 bits.
+/// The struct is declared with `#[repr(transparent)]`, sharing the layout of its storage type,
+/// unless a custom `#[repr(...)]` attribute is given.
 /// A variable amount of getters and or setters can be specified on a bitwise level.
 /// Every operation automatically ensures that no bounds are being violated.
+/// Fields exceeding the storage type, with their msb below their lsb or being wider than their
+/// value type are rejected at compile-time.
+///
+/// Fields may be converted from and into custom types using `from`, `into` and `from into`, which
+/// rely on `From`, or using `try_from`, `try_into` and `try_from try_into`, which rely on `TryFrom`.
+/// Getters and setters of fallibly converted fields return a `Result` with the conversion error.
+///
+/// For every setter, a fallible `try_` variant (e.g. `try_set_field1`) is generated as well,
+/// which returns a [`BitfieldError`] instead of panicking when a value does not fit.
+///
+/// Additionally, a consuming `with_` builder (e.g. `with_field1`) is generated for every setter,
+/// named after the getter of the field or after the setter if the field has no getter.
+/// These allow building bitfields within a single `const` expression:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u16);
+///     u8, field1, set_field1: 7, 0;
+///     bool, field2, set_field2: 8;
+/// }
+///
+/// const VALUE: BitField = BitField(0).with_field1(0x2A).with_field2(true);
+/// assert_eq!(VALUE.0, 0x12A);
+/// ```
+///
+/// For every field, the associated constants `<FIELD>_MASK` (typed as the storage), `<FIELD>_SHIFT`
+/// and `<FIELD>_WIDTH` are generated as well, such as `BitField::FIELD1_MASK` for the example above.
 ///
 /// # Example
 /// ```rust
-/// #![feature(const_mut_refs)]
-/// #![feature(const_trait_impl)]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 ///
 /// use const_bitfield::bitfield;
 ///
@@ -38,234 +104,3071 @@
 ///     u8, _, set_field6: 15, 13;      // u8 setter for bits 13..=15
 /// }
 /// ```
+///
+/// # Field Metadata
+/// Every bitfield lists its fields within a `FIELDS` constant of [`FieldInfo`], holding the name,
+/// bit range counted from the lsb, access and raw type name of every field. Field arrays are listed
+/// once with the bit range of their first element, along with a [`FieldArray`] describing all elements.
+/// Tools may use `get_by_name` and `set_by_name` for accessing the raw value of a field by its name,
+/// bypassing any conversion of the field, with array elements being addressed as e.g. `field3[1]`:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::{bitfield, FieldAccess};
+///
+/// bitfield! {
+///     pub struct BitField(u16);
+///     u8, field1, set_field1: 7, 0;
+///     bool, field2, _: 8;
+///     u8, field3, set_field3: [2] 11, 9;
+/// }
+///
+/// assert_eq!(BitField::FIELDS[1].name, "field2");
+/// assert_eq!(BitField::FIELDS[1].access, FieldAccess::Read);
+/// assert_eq!(BitField::FIELDS[2].array.map(|array| array.count), Some(2));
+///
+/// let mut value = BitField(0x100);
+/// value.set_by_name("field1", 0x2A).unwrap().set_by_name("field3[1]", 0x5).unwrap();
+/// assert_eq!(value.get_by_name("field1"), Some(0x2A));
+/// assert_eq!(value.get_by_name("field2"), Some(1));
+/// assert_eq!(value.get_by_name("field3[1]"), Some(0x5));
+/// assert!(value.set_by_name("field2", 0).is_err());
+/// assert!(value.set_by_name("field3[2]", 0).is_err());
+/// ```
+///
+/// # Overlapping Fields
+/// Fields sharing any bit are rejected at compile-time, unless all of them are marked with
+/// `overlap` to explicitly opt into union-like behavior:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u8);
+///     overlap u8, byte, set_byte: 7, 0;
+///     overlap u8, nibble, set_nibble: 3, 0;
+///     pub overlap bool, flag, set_flag: 7;
+/// }
+/// ```
+///
+/// # Nested Bitfields
+/// Fields spanning a bit range may hold another bitfield using `nested Type`, given the raw type of
+/// the field is the storage type of the nested bitfield. Getters return and setters accept the
+/// nested bitfield without requiring any `From` implementation. Additionally, a `_mut` method (e.g.
+/// `permissions_mut`) returns a guard for modifying the nested bitfield in-place, which is written
+/// back once the guard is dropped. All fields of the nested bitfield must fit into the bit range,
+/// which is checked at compile-time, while raw bits beyond the bit range are discarded on write-back:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct Permissions(u8);
+///     bool, read, set_read: 0;
+///     bool, write, set_write: 1;
+/// }
+///
+/// bitfield! {
+///     pub struct Entry(u16);
+///     bool, present, set_present: 0;
+///     u8, nested Permissions, permissions, set_permissions: 2, 1;
+/// }
+///
+/// let mut entry = Entry(0);
+/// entry.set_permissions(Permissions(0).with_read(true));
+/// entry.permissions_mut().set_write(true);
+/// assert_eq!(entry.0, 0b110);
+/// assert!(entry.permissions().write());
+/// ```
+///
+/// # Default Values
+/// Fields may declare a default value using `= value`, which is given as the type accepted by the
+/// setter. Declaring default values generates a `const fn new()` and an implementation of `Default`,
+/// both returning a bitfield with all fields set to their default values and all other bits cleared.
+/// Additionally, a `reset_` method (e.g. `reset_field1`) is generated for every field with default
+/// value and setter, while fields without setter only receive their default value within `new()`:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u16);
+///     u8, field1, set_field1: 7, 0 = 0x2A;
+///     bool, field2, set_field2: 8 = true;
+///     bool, field3, set_field3: 9;
+/// }
+///
+/// const VALUE: BitField = BitField::new();
+/// assert_eq!(VALUE.0, 0x12A);
+///
+/// let mut value = BitField::default();
+/// value.set_field1(0xFF).reset_field1();
+/// assert_eq!(value.field1(), 0x2A);
+/// ```
+///
+/// # Reserved Bits
+/// Bits which must always be zero or one can be declared using `reserved 0: msb, lsb;` and
+/// `reserved 1: bit;`. Declaring reserved bits generates a `RESERVED_MASK` constant, an `is_valid`
+/// method checking all reserved bits and a `sanitize` method forcing them to their required values:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u8);
+///     u8, field1, set_field1: 3, 0;
+///     reserved 0: 6, 4;
+///     reserved 1: 7;
+/// }
+///
+/// assert_eq!(BitField::RESERVED_MASK, 0xF0);
+/// assert!(!BitField(0x1F).is_valid());
+/// assert_eq!(BitField(0x1F).sanitize().0, 0x8F);
+/// ```
+///
+/// # Field Arrays
+/// Repeated fields can be declared as an array using `[count] msb, lsb stride n;`, where the given
+/// bit positions describe the first element and every further element is shifted by `stride` bits.
+/// Without `stride`, elements directly follow each other. Array getters and setters take the index
+/// of the element as first argument and panic if it is out of bounds, and a `_COUNT` constant holds
+/// the number of elements. No `try_`, `with_` or `reset_` methods are generated for arrays:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u32);
+///     u8, prio, set_prio: [4] 2, 0 stride 4;  // u8 getter/setter for bits 0..=2, 4..=6, ...
+///     bool, flag, set_flag: [8] 24;           // bool getter/setter for bits 24..=31
+/// }
+///
+/// let mut value = BitField(0);
+/// value.set_prio(1, 0x5).set_flag(7, true);
+/// assert_eq!(value.0, 0x8000_0050);
+/// assert_eq!(BitField::PRIO_COUNT, 4);
+/// ```
+///
+/// # Debug
+/// Adding `impl Debug;` to the list of fields implements `core::fmt::Debug`, printing the raw
+/// value in hexadecimal followed by the value of every field with a getter, with the elements of
+/// field arrays being listed like a slice:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u16);
+///     impl Debug;
+///     u8, field1, set_field1: 7, 0;
+///     bool, field2, _: 8;
+///     bool, flag, _: [2] 9;
+/// }
+///
+/// assert_eq!(
+///     format!("{:?}", BitField(0x3AB)),
+///     "BitField { raw: 0x03ab, field1: 171, field2: true, flag: [true, false] }"
+/// );
+/// ```
+///
+/// # Register Diagram
+/// Adding `impl Diagram;` to the list of fields generates a `diagram()` method returning a
+/// [`Diagram`], which displays the storage like a register figure of a datasheet. Bits are rendered
+/// in rows of up to 32 bits, listing the name and current raw value of every field, with elements of
+/// field arrays shown as e.g. `field[1]`, while names not fitting into their bit range are truncated:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u8);
+///     impl Diagram;
+///     u8, field1, set_field1: 3, 0;
+///     bool, field2, _: 7;
+/// }
+///
+/// assert_eq!(
+///     BitField(0x8A).diagram().to_string(),
+///     "  7   6   5   4   3   2   1   0 \n\
+///      +---+-----------+---------------+\n\
+///      |fie|           |    field1     |\n\
+///      | 1 |    0x0    |      0xa      |\n\
+///      +---+-----------+---------------+"
+/// );
+/// ```
+///
+/// # Bitwise Operators
+/// Adding `impl BitOps;` to the list of fields implements `BitAnd`, `BitOr`, `BitXor`, `Not` and
+/// their `*Assign` variants between bitfields of the same type, as well as `BitAnd` with the storage
+/// type for masking with raw values such as the `_MASK` constants. On Rust nightly, these are const
+/// trait implementations, which additionally require `#![feature(const_ops)]`:
+#[cfg_attr(not(feature = "stable"), doc = "```rust")]
+#[cfg_attr(feature = "stable", doc = "```ignore")]
+/// #![feature(const_mut_refs)]
+/// #![feature(const_ops)]
+/// #![feature(const_trait_impl)]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     #[derive(Copy, Clone)]
+///     pub struct BitField(u16);
+///     impl BitOps;
+///     u8, field1, set_field1: 7, 0;
+///     bool, field2, set_field2: 8;
+/// }
+///
+/// const VALUE: BitField = BitField(0).with_field1(0x2A) | BitField(0).with_field2(true);
+/// assert_eq!(VALUE.0, 0x12A);
+/// assert_eq!((!VALUE & BitField::FIELD1_MASK).0, 0xD5);
+/// ```
+///
+/// # Embedded Formatting
+/// With the `defmt` or `ufmt` feature enabled, adding `impl Format;` or `impl uDebug;` to the list
+/// of fields implements `defmt::Format` or `ufmt::uDebug` respectively, listing the raw value and
+/// every field with a getter just like `impl Debug;`. Field values must implement the same trait,
+/// and the generated `defmt::Format` implementation requires `defmt` as a direct dependency.
+/// As `defmt` only accepts string literals as format strings, which can not be assembled from the
+/// field names by the macro, the struct and field names are transmitted as `{=str}` arguments.
+/// Every logged bitfield therefore carries these names on the wire, in addition to its values.
+///
+/// # Serde
+/// With the `serde` feature enabled, adding `impl Serde;` to the list of fields implements
+/// `Serialize` and `Deserialize` using a map of every field with a getter, holding the value as
+/// returned by the getter. Deserialization starts from a cleared bitfield and applies every given
+/// field through its `try_` setter, rejecting unknown fields and values which do not fit, while values
+/// of fields without setter are ignored. Alternatively, `impl SerdeRaw;` (de)serializes the raw value
+/// of the storage instead. Field arrays are represented as a sequence of all their elements, which
+/// must hold exactly as many elements as the array when deserializing:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// # #[cfg(feature = "serde")]
+/// # mod example {
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     pub struct BitField(u16);
+///     impl Serde;
+///     u8, field1, set_field1: 7, 0;   // serialized as `{"field1": 171, "field2": true, "flag": [true, false]}`
+///     bool, field2, set_field2: 8;
+///     bool, flag, set_flag: [2] 9;
+/// }
+/// # }
+/// ```
+///
+/// # Bit Order
+/// Bit positions are counted from the least significant bit by default. Specifications which
+/// number bit 0 as the most significant bit can be transcribed as-is by adding `#[bit_order(msb0)]`
+/// to the struct, in which case fields still list their most significant bit first:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     #[bit_order(msb0)]
+///     pub struct Ipv4Header(u32);
+///     u8, version, set_version: 0, 3;     // u8 getter/setter for bits 28..=31
+///     u8, ihl, set_ihl: 4, 7;             // u8 getter/setter for bits 24..=27
+///     u16, length, set_length: 16, 31;    // u16 getter/setter for bits 0..=15
+/// }
+/// ```
+///
+/// Array storage is numbered as a single integer whose elements are ordered from the least to the
+/// most significant one, so with `msb0` bit 0 is the most significant bit of the *last* element.
+/// Buffers in wire order such as `[u8; N]` in network byte order start with the most significant
+/// byte instead and should be converted using `from_be_bytes` and `to_be_bytes`:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+///
+/// bitfield! {
+///     #[bit_order(msb0)]
+///     pub struct UdpHeader([u8; 8]);
+///     u16, source_port, set_source_port: 0, 15;
+///     u16, checksum, set_checksum: 48, 63;
+/// }
+///
+/// let header = UdpHeader::from_be_bytes([0x12, 0x34, 0, 0, 0, 0, 0xAB, 0xCD]);
+/// assert_eq!(header.source_port(), 0x1234);
+/// assert_eq!(header.checksum(), 0xABCD);
+/// assert_eq!(header.0, [0xCD, 0xAB, 0, 0, 0, 0, 0x34, 0x12]);
+/// ```
+///
+/// # Atomic Storage
+/// Bitfields shared between threads or interrupt handlers may be backed by `AtomicU8`, `AtomicU16`,
+/// `AtomicU32` or `AtomicU64`, which must be named exactly like this. Getters and setters then take
+/// `&self` along with an additional [`Ordering`](core::sync::atomic::Ordering). Getters perform a
+/// single load, while setters use `fetch_or` and `fetch_and` for single bits and a compare-exchange
+/// loop for bit ranges, so concurrent updates of different fields never overwrite each other. No
+/// `with_` builders, `get_by_name` or `set_by_name` methods are generated, and directives, default
+/// values, reserved bits and field arrays are not supported:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::bitfield;
+/// use core::sync::atomic::{AtomicU32, Ordering};
+///
+/// bitfield! {
+///     pub struct State(AtomicU32);
+///     bool, ready, set_ready: 0;
+///     u8, count, set_count: 15, 8;
+/// }
+///
+/// let state = State(AtomicU32::new(0));
+/// state.set_ready(true, Ordering::Release).set_count(3, Ordering::Relaxed);
+/// assert!(state.ready(Ordering::Acquire));
+/// assert_eq!(state.0.load(Ordering::Relaxed), 0x301);
+/// ```
 #[macro_export]
 macro_rules! bitfield {
     // Generate new bitfield with getters and setters
-    ($(#[$attributes:meta])* $visibility:vis struct $name:ident($type:ty); $($fields:tt)*) => {
-        $(#[$attributes])*
+    ($(#[$($attributes:tt)*])* $visibility:vis struct $name:ident($($type:tt)+); $($fields:tt)*) => {
+        $crate::bitfield! {@struct [] [lsb0] [#[repr(transparent)]] $(#[$($attributes)*])* $visibility struct $name($($type)+); $($fields)*}
+    };
+
+    // Struct: Extract bit order marker from struct attributes
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] #[bit_order($new_order:ident)] $($rest:tt)*) => {
+        $crate::bitfield! {@struct [$($attributes)*] [$new_order] [$($repr)*] $($rest)*}
+    };
+
+    // Struct: Keep a custom representation and omit the default `#[repr(transparent)]`
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] #[repr($($new_repr:tt)*)] $($rest:tt)*) => {
+        $crate::bitfield! {@struct [$($attributes)* #[repr($($new_repr)*)]] [$order] [] $($rest)*}
+    };
+
+    // Struct: Collect any other struct attributes
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] #[$attribute:meta] $($rest:tt)*) => {
+        $crate::bitfield! {@struct [$($attributes)* #[$attribute]] [$order] [$($repr)*] $($rest)*}
+    };
+
+    // Struct: Generate struct backed by an atomic integer once all attributes are processed
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident(AtomicU8); $($fields:tt)*) => {
+        $crate::bitfield! {@atomic [$($attributes)*] [$order] [$($repr)*] $visibility struct $name(AtomicU8, u8); $($fields)*}
+    };
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident(AtomicU16); $($fields:tt)*) => {
+        $crate::bitfield! {@atomic [$($attributes)*] [$order] [$($repr)*] $visibility struct $name(AtomicU16, u16); $($fields)*}
+    };
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident(AtomicU32); $($fields:tt)*) => {
+        $crate::bitfield! {@atomic [$($attributes)*] [$order] [$($repr)*] $visibility struct $name(AtomicU32, u32); $($fields)*}
+    };
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident(AtomicU64); $($fields:tt)*) => {
+        $crate::bitfield! {@atomic [$($attributes)*] [$order] [$($repr)*] $visibility struct $name(AtomicU64, u64); $($fields)*}
+    };
+
+    // Struct: Generate struct with getters and setters once all attributes are processed
+    (@struct [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident($type:ty); $($fields:tt)*) => {
+        $($attributes)*
+        $($repr)*
         $visibility struct $name(pub $type);
 
-        $crate::bitfield! {@impl_range struct $name($type)}
-        impl $name {
-            $crate::bitfield! {@fields @getter $($fields)*}
-            $crate::bitfield! {@fields @setter $($fields)*}
-        }
-    };
+        $crate::__bitfield_impl_range! {struct $name($type)}
+        $crate::bitfield! {@impl_bytes $visibility struct $name($type)}
+        impl $name {
+            $crate::bitfield! {@fields @{getter, $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{setter, $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{with, $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{[consts $type], $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{resets, $order, $type} $($fields)*}
+        }
+        $crate::bitfield! {@impl_fields $visibility struct $name($type), $order, [$($fields)*]}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($fields)*]], $order, $type} $($fields)*}
+        $crate::bitfield! {@fields @{[reserved $visibility $name [$($fields)*]], $order, $type} $($fields)*}
+        $crate::bitfield! {@fields @{[defaults $visibility $name [$($fields)*]], $order, $type} $($fields)*}
+
+        const _: () = {
+            const FIELDS: $crate::FieldRanges = $crate::bitfield! {@fields @{[ranges], $order, $type} $($fields)*};
+            $crate::bitfield! {@fields @{[check FIELDS], $order, $type} $($fields)*}
+        };
+    };
+
+    // Atomic: Generate struct(pub AtomicT) with getters and setters taking a memory ordering
+    (@atomic [$($attributes:tt)*] [$order:ident] [$($repr:tt)*] $visibility:vis struct $name:ident($atomic:ident, $type:ty); $($fields:tt)*) => {
+        $($attributes)*
+        $($repr)*
+        $visibility struct $name(pub ::core::sync::atomic::$atomic);
+
+        impl $name {
+            $crate::bitfield! {@fields @{[atomic_getter $type], $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{[atomic_setter $type], $order, $type} $($fields)*}
+            $crate::bitfield! {@fields @{[consts $type], $order, $type} $($fields)*}
+
+            /// Metadata of all fields, with bit positions counted from the lsb.
+            $visibility const FIELDS: &'static [$crate::FieldInfo] = $crate::bitfield! {@fields @{[info []], $order, $type} $($fields)*};
+        }
+
+        const _: () = {
+            const FIELDS: $crate::FieldRanges = $crate::bitfield! {@fields @{[ranges], $order, $type} $($fields)*};
+            $crate::bitfield! {@fields @{[check FIELDS], $order, $type} $($fields)*}
+        };
+    };
+
+    // Impl: Implement conversions from and into byte arrays for struct(pub T)
+    (@impl_bytes $visibility:vis struct $name:ident($type:ty)) => {
+        impl $name {
+            /// Create a bitfield from its representation as a byte array in little-endian byte order.
+            $visibility const fn from_le_bytes(bytes: [u8; ::core::mem::size_of::<$type>()]) -> Self {
+                let mut value = <$type as $crate::BitStorage>::ZERO;
+                let mut index = 0;
+                while index < bytes.len() {
+                    $crate::ops::set_bits(&mut value, index * 8 + 7, index * 8, bytes[index]);
+                    index += 1;
+                }
+                Self(value)
+            }
+
+            /// Create a bitfield from its representation as a byte array in big-endian byte order.
+            $visibility const fn from_be_bytes(bytes: [u8; ::core::mem::size_of::<$type>()]) -> Self {
+                let mut value = <$type as $crate::BitStorage>::ZERO;
+                let mut index = 0;
+                while index < bytes.len() {
+                    let offset = (bytes.len() - 1 - index) * 8;
+                    $crate::ops::set_bits(&mut value, offset + 7, offset, bytes[index]);
+                    index += 1;
+                }
+                Self(value)
+            }
+
+            /// Create a bitfield from its representation as a byte array in native byte order.
+            $visibility const fn from_ne_bytes(bytes: [u8; ::core::mem::size_of::<$type>()]) -> Self {
+                if cfg!(target_endian = "big") {
+                    Self::from_be_bytes(bytes)
+                } else {
+                    Self::from_le_bytes(bytes)
+                }
+            }
+
+            /// Return the representation of this bitfield as a byte array in little-endian byte order.
+            $visibility const fn to_le_bytes(&self) -> [u8; ::core::mem::size_of::<$type>()] {
+                let mut bytes = [0; ::core::mem::size_of::<$type>()];
+                let mut index = 0;
+                while index < bytes.len() {
+                    bytes[index] = $crate::ops::bits(&self.0, index * 8 + 7, index * 8);
+                    index += 1;
+                }
+                bytes
+            }
+
+            /// Return the representation of this bitfield as a byte array in big-endian byte order.
+            $visibility const fn to_be_bytes(&self) -> [u8; ::core::mem::size_of::<$type>()] {
+                let mut bytes = [0; ::core::mem::size_of::<$type>()];
+                let mut index = 0;
+                while index < bytes.len() {
+                    let offset = (bytes.len() - 1 - index) * 8;
+                    bytes[index] = $crate::ops::bits(&self.0, offset + 7, offset);
+                    index += 1;
+                }
+                bytes
+            }
+
+            /// Return the representation of this bitfield as a byte array in native byte order.
+            $visibility const fn to_ne_bytes(&self) -> [u8; ::core::mem::size_of::<$type>()] {
+                if cfg!(target_endian = "big") {
+                    self.to_be_bytes()
+                } else {
+                    self.to_le_bytes()
+                }
+            }
+        }
+    };
+
+    // Impl: Implement field metadata along with access to raw field values by name
+    (@impl_fields $visibility:vis struct $name:ident($type:ty), $order:ident, [$($fields:tt)*]) => {
+        impl $name {
+            /// Metadata of all fields, with bit positions counted from the lsb.
+            $visibility const FIELDS: &'static [$crate::FieldInfo] = $crate::bitfield! {@fields @{[info []], $order, $type} $($fields)*};
+
+            /// Get the raw value of the readable field with the given name, bypassing any conversion.
+            $visibility fn get_by_name(&self, name: &str) -> Option<u128> {
+                match $crate::FieldInfo::find(Self::FIELDS, name) {
+                    Some((field, msb, lsb)) if field.access.is_readable() => Some($crate::ops::bits(&self.0, msb, lsb)),
+                    _ => None,
+                }
+            }
+
+            /// Set the raw value of the writable field with the given name, bypassing any conversion.
+            $visibility fn set_by_name(&mut self, name: &str, value: u128) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                match $crate::FieldInfo::find(Self::FIELDS, name) {
+                    Some((field, msb, lsb)) if field.access.is_writable() => match $crate::ops::try_set_bits(&mut self.0, msb, lsb, value) {
+                        Ok(()) => Ok(self),
+                        Err(error) => Err(error),
+                    },
+                    _ => Err($crate::BitfieldError::UnknownField),
+                }
+            }
+        }
+    };
+
+    // Impl: Implement bitwise operators for struct(pub T), along with masking using raw values of T
+    (@impl_bitops struct $name:ident($type:ty)) => {
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitAnd, for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(<$type as $crate::BitStorage>::and(self.0, rhs.0))
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitAnd<$type>, for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: $type) -> Self {
+                Self(<$type as $crate::BitStorage>::and(self.0, rhs))
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitOr, for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(<$type as $crate::BitStorage>::or(self.0, rhs.0))
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitXor, for $name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(<$type as $crate::BitStorage>::xor(self.0, rhs.0))
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::Not, for $name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self(<$type as $crate::BitStorage>::not(self.0))
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitAndAssign, for $name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 = <$type as $crate::BitStorage>::and(self.0, rhs.0);
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitOrAssign, for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 = <$type as $crate::BitStorage>::or(self.0, rhs.0);
+            }
+        }}
+
+        $crate::__bitfield_impl_const! {impl ::core::ops::BitXorAssign, for $name {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.0 = <$type as $crate::BitStorage>::xor(self.0, rhs.0);
+            }
+        }}
+    };
+
+    // Impl: Implement Debug listing every field with a getter along with the raw value
+    (@impl_debug struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct(::core::stringify!($name));
+                debug.field("raw", &$crate::DebugHex(&self.0));
+                $crate::bitfield! {@fields @{[debug debug self], $order, $type} $($fields)*}
+                debug.finish()
+            }
+        }
+    };
+
+    // Impl: Implement defmt::Format listing every field with a getter along with the raw value
+    (@impl_defmt struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl $crate::defmt::Format for $name {
+            fn format(&self, f: $crate::defmt::Formatter<'_>) {
+                // names are passed as arguments, as defmt::write! requires a literal format string
+                $crate::defmt::write!(f, "{=str} {{ raw: {}", ::core::stringify!($name), $crate::DebugHex(&self.0));
+                $crate::bitfield! {@fields @{[defmt f self], $order, $type} $($fields)*}
+                $crate::defmt::write!(f, " }}");
+            }
+        }
+    };
+
+    // Impl: Implement ufmt::uDebug listing every field with a getter along with the raw value
+    (@impl_udebug struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl $crate::ufmt::uDebug for $name {
+            fn fmt<W: $crate::ufmt::uWrite + ?Sized>(&self, f: &mut $crate::ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error> {
+                let mut debug = f.debug_struct(::core::stringify!($name))?;
+                debug.field("raw", &$crate::DebugHex(&self.0))?;
+                $crate::bitfield! {@fields @{[udebug debug self], $order, $type} $($fields)*}
+                debug.finish()
+            }
+        }
+    };
+
+    // Impl: Implement Serialize and Deserialize using a map of every field with a getter
+    (@impl_serde struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl $crate::serde::Serialize for $name {
+            fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                let mut len = 0;
+                $crate::bitfield! {@fields @{[serde_len len], $order, $type} $($fields)*}
+                let mut map = serializer.serialize_map(Some(len))?;
+                $crate::bitfield! {@fields @{[serialize map self], $order, $type} $($fields)*}
+                $crate::serde::ser::SerializeMap::end(map)
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> $crate::serde::de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(::core::concat!("a map of the fields of `", ::core::stringify!($name), "`"))
+                    }
+
+                    fn visit_map<A: $crate::serde::de::MapAccess<'de>>(self, mut map: A) -> ::core::result::Result<$name, A::Error> {
+                        fn known(key: &str) -> Option<&'static str> {
+                            $crate::bitfield! {@fields @{[serde_known key], $order, $type} $($fields)*}
+                            None
+                        }
+
+                        let mut value = $name(<$type as $crate::BitStorage>::ZERO);
+                        while let Some(key) = map.next_key_seed($crate::serde::FieldKey(known))? {
+                            $crate::bitfield! {@fields @{[deserialize map value key], $order, $type} $($fields)*}
+                        }
+                        Ok(value)
+                    }
+                }
+
+                deserializer.deserialize_map(Visitor)
+            }
+        }
+    };
+
+    // Impl: Implement Serialize and Deserialize using the raw value
+    (@impl_serde_raw struct $name:ident, $type:ty) => {
+        impl $crate::serde::Serialize for $name {
+            fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                <$type as $crate::serde::Serialize>::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                <$type as $crate::serde::Deserialize<'de>>::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+
+    // Impl: Implement validation and sanitization of reserved bits
+    (@impl_reserved $visibility:vis struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl $name {
+            /// Bit mask of all reserved bits within the storage.
+            $visibility const RESERVED_MASK: $type = {
+                let mut mask = <$type as $crate::BitStorage>::ZERO;
+                $crate::bitfield! {@fields @{[reserved mask mask], $order, $type} $($fields)*}
+                mask
+            };
+
+            /// Check whether all reserved bits are set to their required values.
+            $visibility const fn is_valid(&self) -> bool {
+                $crate::bitfield! {@fields @{[reserved check self], $order, $type} $($fields)*}
+                true
+            }
+
+            /// Force all reserved bits to their required values.
+            $visibility const fn sanitize(mut self) -> Self {
+                $crate::bitfield! {@fields @{[reserved set self], $order, $type} $($fields)*}
+                self
+            }
+        }
+    };
+
+    // Impl: Implement constructor and Default using default values of fields
+    (@impl_default $visibility:vis struct $name:ident, $order:ident, $type:ty, [$($fields:tt)*]) => {
+        impl $name {
+            /// Create a bitfield with all fields set to their default values.
+            $visibility const fn new() -> Self {
+                let mut value = Self(<$type as $crate::BitStorage>::ZERO);
+                $crate::bitfield! {@fields @{[reset value], $order, $type} $($fields)*}
+                value
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        const _: $name = $name::new();
+    };
+
+    // Position: Bit positions are counted from the least significant bit
+    (@position lsb0, $storage:ty, $position:expr) => {
+        $position
+    };
+
+    // Position: Bit positions are counted from the most significant bit
+    (@position msb0, $storage:ty, $position:expr) => {
+        (::core::mem::size_of::<$storage>() * 8 - 1).wrapping_sub($position)
+    };
+
+    // Directives: Implement Debug trait
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl Debug; $($rest:tt)*) => {
+        $crate::bitfield! {@impl_debug struct $name, $order, $storage, [$($all)*]}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement register diagram
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl Diagram; $($rest:tt)*) => {
+        impl $name {
+            /// Render the storage as a register diagram listing every field with its current raw value.
+            $visibility fn diagram(&self) -> $crate::Diagram<'_, $storage> {
+                $crate::Diagram::new(&self.0, Self::FIELDS)
+            }
+        }
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement bitwise operator traits
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl BitOps; $($rest:tt)*) => {
+        $crate::bitfield! {@impl_bitops struct $name($storage)}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement defmt::Format trait
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl Format; $($rest:tt)*) => {
+        $crate::__bitfield_defmt! {impl Format; $crate::bitfield! {@impl_defmt struct $name, $order, $storage, [$($all)*]}}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement ufmt::uDebug trait
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl uDebug; $($rest:tt)*) => {
+        $crate::__bitfield_ufmt! {impl uDebug; $crate::bitfield! {@impl_udebug struct $name, $order, $storage, [$($all)*]}}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement Serialize and Deserialize using a map of named fields
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl Serde; $($rest:tt)*) => {
+        $crate::__bitfield_serde! {impl Serde; $crate::bitfield! {@impl_serde struct $name, $order, $storage, [$($all)*]}}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Implement Serialize and Deserialize using the raw value
+    (@fields @{[impl $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} impl SerdeRaw; $($rest:tt)*) => {
+        $crate::__bitfield_serde! {impl SerdeRaw; $crate::bitfield! {@impl_serde_raw struct $name, $storage}}
+        $crate::bitfield! {@fields @{[impl $visibility $name [$($all)*]], $order, $storage} $($rest)*}
+    };
+
+    // Directives: Reject directives for atomic storage
+    (@fields @{[atomic_getter $atomic:ty], $order:ident, $storage:ty} impl $directive:ident; $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("`impl ", ::core::stringify!($directive), ";` is not supported for atomic storage"));
+    };
+
+    // Directives: Skip directives when processing fields
+    (@fields @$variant:tt impl $directive:ident; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $($rest)*}
+    };
+
+    // Reserved: Implement reserved bit handling once the first reserved declaration is found
+    (@fields @{[reserved $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@impl_reserved $visibility struct $name, $order, $storage, [$($all)*]}
+    };
+
+    // Reserved: Add reserved bits to mask
+    (@fields @{[reserved mask $mask:ident], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        {
+            let (msb, lsb) = $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*);
+            $crate::ops::set_bits(&mut $mask, msb, lsb, u128::MAX >> (127 - (msb - lsb)));
+        }
+        $crate::bitfield! {@fields @{[reserved mask $mask], $order, $storage} $($rest)*}
+    };
+
+    // Reserved: Return early if reserved bits differ from their required value
+    (@fields @{[reserved check $this:tt], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        {
+            let (msb, lsb) = $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*);
+            let bits: u128 = $crate::ops::bits(&$this.0, msb, lsb);
+            if bits != $crate::bitfield!(@reserved $value, msb, lsb) {
+                return false;
+            }
+        }
+        $crate::bitfield! {@fields @{[reserved check $this], $order, $storage} $($rest)*}
+    };
+
+    // Reserved: Force reserved bits to their required value
+    (@fields @{[reserved set $this:tt], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        {
+            let (msb, lsb) = $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*);
+            $crate::ops::set_bits(&mut $this.0, msb, lsb, $crate::bitfield!(@reserved $value, msb, lsb));
+        }
+        $crate::bitfield! {@fields @{[reserved set $this], $order, $storage} $($rest)*}
+    };
+
+    // Reserved: Collect bit range of reserved bits for overlap detection
+    (@fields @{[ranges], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::FieldRanges::Field {
+            range: $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*),
+            overlap: false,
+            next: &$crate::bitfield! {@fields @{[ranges], $order, $storage} $($rest)*},
+        }
+    };
+
+    // Reserved: Ensure reserved bits are valid and do not overlap with any field
+    (@fields @{[check $ranges:ident], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        const _: () = assert!($value == 0 || $value == 1, "reserved bits must be either 0 or 1");
+        const _: () = assert!(
+            !$ranges.conflicts($crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*), false),
+            "reserved field overlaps with another field",
+        );
+        $crate::bitfield! {@validate $order, $storage, u128, ["reserved field"], $($exprs),*}
+        $crate::bitfield! {@fields @{[check $ranges], $order, $storage} $($rest)*}
+    };
+
+    // Reserved: Reject reserved bits for atomic storage
+    (@fields @{[atomic_getter $atomic:ty], $order:ident, $storage:ty} reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        ::core::compile_error!("reserved bits are not supported for atomic storage");
+    };
+
+    // Reserved: Skip reserved bits when processing fields
+    (@fields @$variant:tt reserved $value:literal: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $($rest)*}
+    };
+
+    // Parse Fields: Process fields which are explicitly allowed to overlap with other fields
+    (@fields @{$variant:tt, $order:ident, $storage:ty} $(#[$attributes:meta])* $visibility:vis overlap $($rest:tt)*) => {
+        $crate::bitfield! {@fields @{$variant, $order, $storage, overlap} $(#[$attributes])* $visibility $($rest)*}
+    };
+
+    // Parse Fields: Process arrays of nested bitfields
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, nested $nested:path, $getter:tt, $setter:tt: [$($array:tt)*] $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (nested $nested), (nested $nested), $getter, $setter: [$($array)*] $($tail)*}
+    };
+
+    // Parse Fields: Process nested bitfields
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, nested $nested:path, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (nested $nested), (nested $nested), $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process regular fields without from/into conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, _, _, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with from conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from $from:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $type, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with into conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $type, $into, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with from and into conversion for same type
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from into $from_into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from_into, $from_into, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with from and into conversion for different types
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from $from:ty, into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with fallible try_from conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, try_from $from:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (try $from), $type, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with fallible try_into conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, try_into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $type, (try $into), $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with fallible try_from and try_into conversion for same type
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, try_from try_into $from_into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (try $from_into), (try $from_into), $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with fallible try_from and try_into conversion for different types
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, try_from $from:ty, try_into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (try $from), (try $into), $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with infallible from and fallible try_into conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from $from:ty, try_into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, (try $into), $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process fields with fallible try_from and infallible into conversion
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, try_from $from:ty, into $into:ty, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, (try $from), $into, $getter, $setter: $($tail)*}
+    };
+
+    // Parse Fields: Process field arrays with explicit stride
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr] $($exprs:tt),+ stride $stride:expr; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: [$count; $stride] $($exprs),+; $($rest)*}
+    };
+
+    // Parse Fields: Process field arrays with elements following each other
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr] $($exprs:tt),+; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: [$count; $crate::bitfield!(@width $($exprs),+)] $($exprs),+; $($rest)*}
+    };
+
+    // Arrays: Reject field arrays for atomic storage
+    (@fields @{[atomic_getter $atomic:ty], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("field `", $crate::bitfield!(@name $getter, $setter), "` is an array, which is not supported for atomic storage"));
+    };
+
+    // Arrays: Generate indexed getter
+    (@fields @{getter, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @getter [$order, $storage, $count, $stride] $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*}
+        $crate::bitfield! {@fields @{getter, $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Generate indexed setter
+    (@fields @{setter, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @setter [$order, $storage, $count, $stride] $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*}
+        $crate::bitfield! {@fields @{setter, $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Generate constant with number of elements
+    (@fields @{[consts $cstorage:ty], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @count [$count] $visibility $getter, $setter}
+        $crate::bitfield! {@fields @{[consts $cstorage], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Collect bit ranges of all elements into a list for overlap detection
+    (@fields @{[ranges], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::FieldRanges::Array {
+            range: $crate::bitfield!(@element $order, $storage, $stride, 0, $($exprs),*),
+            count: $count,
+            step: $crate::bitfield!(@step $order, $stride),
+            overlap: $crate::bitfield!(@overlap $($marker)?),
+            next: &$crate::bitfield! {@fields @{[ranges], $order, $storage} $($rest)*},
+        }
+    };
+
+    // Arrays: Ensure no element overlaps with any other field unless explicitly allowed
+    (@fields @{[check $ranges:ident], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        const _: () = {
+            let mut index = 0;
+            while index < $count {
+                assert!(
+                    !$ranges.conflicts(
+                        $crate::bitfield!(@element $order, $storage, $stride, index, $($exprs),*),
+                        $crate::bitfield!(@overlap $($marker)?),
+                    ),
+                    concat!("field `", $crate::bitfield!(@name $getter, $setter), "` overlaps with another field"),
+                );
+                index += 1;
+            }
+        };
+        $crate::bitfield! {@validate $order, $storage, $type, ["field `", $crate::bitfield!(@name $getter, $setter), "`"], $($exprs),*}
+        $crate::bitfield! {@validate $order, $storage, $type, ["field `", $crate::bitfield!(@name $getter, $setter), "`"], $(($exprs) + ($count - 1) * ($stride)),*}
+        $crate::bitfield! {@validate_nested $from, ["field `", $crate::bitfield!(@name $getter, $setter), "`"], $crate::bitfield!(@element $order, $storage, $stride, 0, $($exprs),*)}
+        $crate::bitfield! {@fields @{[check $ranges], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Collect metadata of field array, describing the first element along with all elements
+    (@fields @{[info [$($infos:tt)*]], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @{[info [$($infos)* $crate::FieldInfo {
+            name: $crate::bitfield!(@name $getter, $setter),
+            msb: $crate::bitfield!(@element $order, $storage, $stride, 0, $($exprs),*).0,
+            lsb: $crate::bitfield!(@element $order, $storage, $stride, 0, $($exprs),*).1,
+            access: $crate::bitfield!(@access $getter, $setter),
+            raw_type: ::core::stringify!($type),
+            array: Some($crate::FieldArray {
+                count: $count,
+                step: $crate::bitfield!(@step $order, $stride),
+            }),
+        },]], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Add elements of field array to Debug output
+    (@fields @{[debug $builder:ident $this:tt], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @[debug $builder $this] [$count] $getter}
+        $crate::bitfield! {@fields @{[debug $builder $this], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Add elements of field array to defmt output
+    (@fields @{[defmt $formatter:ident $this:tt], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @[defmt $formatter $this] [$count] $getter}
+        $crate::bitfield! {@fields @{[defmt $formatter $this], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Add elements of field array to uDebug output
+    (@fields @{[udebug $builder:ident $this:tt], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @[udebug $builder $this] [$count] $getter}
+        $crate::bitfield! {@fields @{[udebug $builder $this], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Count field array as single entry of serde map
+    (@fields @{[serde_len $len:ident], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@field @[serde_len $len] $type, $from, $into, $getter, $setter: $($exprs),*}
+        $crate::bitfield! {@fields @{[serde_len $len], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Serialize elements of field array as sequence
+    (@fields @{[serialize $map:ident $this:tt], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @[serialize $map $this] [$count] $type, $from, $into, $getter, $setter}
+        $crate::bitfield! {@fields @{[serialize $map $this], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Accept name of field array for serde map
+    (@fields @{[serde_known $key:ident], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@field @[serde_known $key] $type, $from, $into, $getter, $setter: $($exprs),*}
+        $crate::bitfield! {@fields @{[serde_known $key], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Deserialize elements of field array from sequence
+    (@fields @{[deserialize $map:ident $this:ident $key:ident], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@array @[deserialize $map $this $key] [$order, $storage, $count, $stride] $type, $from, $into, $getter, $setter: $($exprs),*}
+        $crate::bitfield! {@fields @{[deserialize $map $this $key], $order, $storage} $($rest)*}
+    };
+
+    // Arrays: Skip field arrays for all other operations
+    (@fields @{$variant:tt, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: [$count:expr; $stride:expr] $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @{$variant, $order, $storage} $($rest)*}
+    };
+
+    // Parse Fields: Extract default value of fields with single token bit positions
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $($exprs:tt),+ = $default:expr; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter [$default]: $($exprs),+; $($rest)*}
+    };
+
+    // Parse Fields: Process fields with single token bit positions without default value
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $($exprs:tt),+; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter []: $($exprs),+; $($rest)*}
+    };
+
+    // Parse Fields: Split bit positions made of multiple tokens off the optional default value
+    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $($tail:tt)*) => {
+        $crate::bitfield! {@positions @$variant [$(#[$attributes])* $visibility $type, $from, $into, $getter, $setter] [] [] $($tail)*}
+    };
+
+    // Defaults: Reject default values for atomic storage
+    (@fields @{[atomic_getter $atomic:ty], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$default:expr]: $($exprs:expr),*; $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("field `", $crate::bitfield!(@name $getter, $setter), "` has a default value, which is not supported for atomic storage"));
+    };
+
+    // Defaults: Implement constructor and Default once the first field with default value is found
+    (@fields @{[defaults $visibility:vis $name:ident [$($all:tt)*]], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $field_visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$default:expr]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@impl_default $visibility struct $name, $order, $storage, [$($all)*]}
+    };
+
+    // Defaults: Apply default value of field within constructor
+    (@fields @{[reset $this:tt], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$default:expr]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@field @[reset $this, $default] $type, $from, $getter, $setter: $($crate::bitfield!(@position $order, $storage, $exprs)),*}
+        $crate::bitfield! {@fields @{[reset $this], $order, $storage} $($rest)*}
+    };
+
+    // Defaults: Generate reset method for field with default value
+    (@fields @{resets, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$default:expr]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@field @[resets $default] $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($crate::bitfield!(@position $order, $storage, $exprs)),*}
+        $crate::bitfield! {@fields @{resets, $order, $storage} $($rest)*}
+    };
+
+    // Fields: Collect bit ranges of all fields into a list for overlap detection
+    (@fields @{[ranges], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$($default:expr)?]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::FieldRanges::Field {
+            range: $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*),
+            overlap: $crate::bitfield!(@overlap $($marker)?),
+            next: &$crate::bitfield! {@fields @{[ranges], $order, $storage} $($rest)*},
+        }
+    };
+
+    // Fields: Collect metadata of all fields into a slice
+    (@fields @{[info [$($infos:tt)*]], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$($default:expr)?]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @{[info [$($infos)* $crate::FieldInfo {
+            name: $crate::bitfield!(@name $getter, $setter),
+            msb: $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*).0,
+            lsb: $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*).1,
+            access: $crate::bitfield!(@access $getter, $setter),
+            raw_type: ::core::stringify!($type),
+            array: None,
+        },]], $order, $storage} $($rest)*}
+    };
+
+    // Fields: Ensure field does not overlap with any other field unless explicitly allowed
+    (@fields @{[check $ranges:ident], $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$($default:expr)?]: $($exprs:expr),*; $($rest:tt)*) => {
+        const _: () = assert!(
+            !$ranges.conflicts(
+                $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*),
+                $crate::bitfield!(@overlap $($marker)?),
+            ),
+            concat!("field `", $crate::bitfield!(@name $getter, $setter), "` overlaps with another field"),
+        );
+        $crate::bitfield! {@validate $order, $storage, $type, ["field `", $crate::bitfield!(@name $getter, $setter), "`"], $($exprs),*}
+        $crate::bitfield! {@validate_nested $from, ["field `", $crate::bitfield!(@name $getter, $setter), "`"], $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, $exprs)),*)}
+        $crate::bitfield! {@fields @{[check $ranges], $order, $storage} $($rest)*}
+    };
+
+    // Fields: Generate getter of nested bitfield along with an in-place modifier
+    (@fields @{getter, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, (nested $nested:path), $into:tt, $getter:tt, $setter:tt [$($default:expr)?]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@nested $(#[$attributes])* $visibility $type, $nested, $getter, $setter: $($crate::bitfield!(@position $order, $storage, $exprs)),*}
+        $crate::bitfield! {@field @getter $(#[$attributes])* $visibility $type, (nested $nested), $into, $getter, $setter: $($crate::bitfield!(@position $order, $storage, $exprs)),*}
+        $crate::bitfield! {@fields @{getter, $order, $storage} $($rest)*}
+    };
+
+    // Fields: Process each field one-by-one by splitting list head off, translating bit positions
+    (@fields @{$variant:tt, $order:ident, $storage:ty $(, $marker:ident)?} $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt [$($default:expr)?]: $($exprs:expr),*; $($rest:tt)*) => {
+        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($crate::bitfield!(@position $order, $storage, $exprs)),*}
+        $crate::bitfield! {@fields @{$variant, $order, $storage} $($rest)*}
+    };
+
+    // Positions: Complete bit position once a comma is found
+    (@positions @$variant:tt [$($head:tt)*] [$($positions:tt)*] [$($position:tt)+] , $($tail:tt)*) => {
+        $crate::bitfield! {@positions @$variant [$($head)*] [$($positions)* ($($position)+),] [] $($tail)*}
+    };
+
+    // Positions: Complete field with default value once the last bit position is followed by it
+    (@positions @$variant:tt [$($head:tt)*] [$($positions:tt)*] [$($position:tt)+] = $default:expr; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $($head)* [$default]: $($positions)* ($($position)+); $($rest)*}
+    };
+
+    // Positions: Complete field without default value once the end of the field is found
+    (@positions @$variant:tt [$($head:tt)*] [$($positions:tt)*] [$($position:tt)+] ; $($rest:tt)*) => {
+        $crate::bitfield! {@fields @$variant $($head)* []: $($positions)* ($($position)+); $($rest)*}
+    };
+
+    // Positions: Collect tokens of current bit position
+    (@positions @$variant:tt [$($head:tt)*] [$($positions:tt)*] [$($position:tt)*] $token:tt $($tail:tt)*) => {
+        $crate::bitfield! {@positions @$variant [$($head)*] [$($positions)*] [$($position)* $token] $($tail)*}
+    };
+
+    // Fields: Terminate slice of field metadata once all fields are processed
+    (@fields @{[info [$($infos:tt)*]], $order:ident, $storage:ty}) => {
+        &[$($infos)*]
+    };
+
+    // Fields: Terminate list of bit ranges once all fields are processed
+    (@fields @{[ranges], $order:ident, $storage:ty}) => {
+        $crate::FieldRanges::End
+    };
+
+    // Fields: Stop case once all fields are processed
+    (@fields @$variant:tt) => {};
+
+    // Validate: Ensure bit range fits into storage and value type
+    (@validate $order:ident, $storage:ty, $type:ty, [$($subject:tt)*], $msb:expr, $lsb:expr) => {
+        const _: () = {
+            let bits = ::core::mem::size_of::<$storage>() * 8;
+            assert!($msb < bits, concat!($($subject)*, " exceeds the bits of the storage type"));
+            assert!($lsb < bits, concat!($($subject)*, " exceeds the bits of the storage type"));
+
+            let msb = $crate::bitfield!(@position $order, $storage, $msb);
+            let lsb = $crate::bitfield!(@position $order, $storage, $lsb);
+            assert!(
+                msb >= lsb,
+                concat!($($subject)*, " has its msb below its lsb"),
+            );
+            assert!(
+                msb - lsb < ::core::mem::size_of::<$type>() * 8,
+                concat!($($subject)*, " is wider than its value type"),
+            );
+        };
+    };
+
+    // Validate: Ensure single bit fits into storage
+    (@validate $order:ident, $storage:ty, $type:ty, [$($subject:tt)*], $bit:expr) => {
+        const _: () = assert!(
+            $bit < ::core::mem::size_of::<$storage>() * 8,
+            concat!($($subject)*, " exceeds the bits of the storage type"),
+        );
+    };
+
+    // Reserved: Required value of reserved bits within a bit range
+    (@reserved $value:literal, $msb:expr, $lsb:expr) => {
+        if $value == 0 { 0 } else { u128::MAX >> (127 - ($msb - $lsb)) }
+    };
+
+    // Default: Raw default value without conversion
+    (@default $type:ty, _, $default:expr, [$getter:tt, $setter:tt]) => {
+        $default
+    };
+
+    // Default: Raw default value with fallible conversion
+    (@default $type:ty, (try $from:ty), $default:expr, [$getter:tt, $setter:tt]) => {
+        match <$type>::try_from($default) {
+            Ok(raw_value) => raw_value,
+            Err(_) => panic!(concat!("default value of field `", $crate::bitfield!(@name $getter, $setter), "` is invalid")),
+        }
+    };
+
+    // Default: Raw default value of nested bitfield
+    (@default $type:ty, (nested $nested:path), $default:expr, [$getter:tt, $setter:tt]) => {{
+        let value: $nested = $default;
+        value.0
+    }};
+
+    // Default: Raw default value with conversion
+    (@default $type:ty, $from:ty, $default:expr, [$getter:tt, $setter:tt]) => {
+        <$type>::from($default)
+    };
+
+    // Width: Number of bits of a field
+    (@width $msb:tt, $lsb:tt) => {
+        (usize::abs_diff($msb, $lsb) + 1)
+    };
+
+    // Width: Number of bits of a single bit field
+    (@width $bit:tt) => {
+        1
+    };
+
+    // Element: Bit range of an array element as inclusive (msb, lsb) tuple
+    (@element $order:ident, $storage:ty, $stride:expr, $index:expr, $($exprs:expr),*) => {
+        $crate::bitfield!(@range $($crate::bitfield!(@position $order, $storage, ($exprs) + ($index) * ($stride))),*)
+    };
+
+    // Step: Offset between the bit ranges of two array elements in storage
+    (@step lsb0, $stride:expr) => {
+        ($stride) as isize
+    };
+
+    // Step: Offset between the bit ranges of two array elements in storage
+    (@step msb0, $stride:expr) => {
+        -(($stride) as isize)
+    };
+
+    // Range: Bit range of a field as inclusive (msb, lsb) tuple
+    (@range $msb:expr, $lsb:expr) => {
+        ($msb, $lsb)
+    };
+
+    // Range: Bit range of a single bit field as inclusive (msb, lsb) tuple
+    (@range $bit:expr) => {
+        ($bit, $bit)
+    };
+
+    // Overlap: Field is explicitly allowed to overlap with other fields
+    (@overlap overlap) => {
+        true
+    };
+
+    // Overlap: Field must not overlap with other fields
+    (@overlap) => {
+        false
+    };
+
+    // Name: Name of a field without getter
+    (@name _, $setter:ident) => {
+        ::core::stringify!($setter)
+    };
+
+    // Name: Name of a field with getter
+    (@name $getter:ident, $setter:tt) => {
+        ::core::stringify!($getter)
+    };
+
+    // Access: Field with setter only
+    (@access _, $setter:ident) => {
+        $crate::FieldAccess::Write
+    };
+
+    // Access: Field with getter only
+    (@access $getter:ident, _) => {
+        $crate::FieldAccess::Read
+    };
+
+    // Access: Field with getter and setter
+    (@access $getter:ident, $setter:ident) => {
+        $crate::FieldAccess::ReadWrite
+    };
+
+    // Nested: Disabled without getter
+    (@nested $(#[$attributes:meta])* $visibility:vis $type:ty, $nested:path, _, $setter:tt: $($exprs:expr),*) => {};
+
+    // Nested: Disabled without setter
+    (@nested $(#[$attributes:meta])* $visibility:vis $type:ty, $nested:path, $getter:ident, _: $($exprs:expr),*) => {};
+
+    // Nested: Modify nested bitfield in-place, writing it back once the guard is dropped
+    (@nested $(#[$attributes:meta])* $visibility:vis $type:ty, $nested:path, $getter:ident, $setter:ident: $msb:expr, $lsb:expr) => {
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility fn [<$getter _mut>](&mut self) -> $crate::NestedMut<'_, Self, $nested> {
+                let value = self.$getter();
+                $crate::NestedMut::new(self, value, |parent, value| {
+                    // discard raw bits beyond the field instead of panicking within drop
+                    let raw_value: $type = $crate::ops::bits(&value.0, $msb - $lsb, 0);
+                    $crate::ops::set_bits(&mut parent.0, $msb, $lsb, raw_value);
+                })
+            }
+        }
+    };
+
+    // Validate Nested: Ensure all fields of a nested bitfield fit into its bit range
+    (@validate_nested (nested $nested:path), [$($subject:tt)*], $range:expr) => {
+        const _: () = {
+            let (msb, lsb) = $range;
+            assert!(
+                $crate::FieldInfo::fit(<$nested>::FIELDS, msb - lsb + 1),
+                concat!($($subject)*, " is too narrow for the fields of its nested bitfield"),
+            );
+        };
+    };
+
+    // Validate Nested: Nothing to validate for fields without nested bitfield
+    (@validate_nested $from:tt, [$($subject:tt)*], $range:expr) => {};
+
+    // Array Count: Name constant after setter for arrays without getter
+    (@array @count [$count:expr] $visibility:vis _, $setter:ident) => {
+        $crate::bitfield! {@array @count [$count] $visibility $setter}
+    };
+
+    // Array Count: Name constant after getter
+    (@array @count [$count:expr] $visibility:vis $getter:ident, $setter:tt) => {
+        $crate::bitfield! {@array @count [$count] $visibility $getter}
+    };
+
+    // Array Count: Number of elements
+    (@array @count [$count:expr] $visibility:vis $field:ident) => {
+        $crate::paste::paste! {
+            #[doc = ::core::concat!("Number of elements of field `", ::core::stringify!($field), "`.")]
+            $visibility const [<$field:upper _COUNT>]: usize = $count;
+        }
+    };
+
+    // Array Debug: Disabled
+    (@array @[debug $builder:ident $this:tt] [$count:expr] _) => {};
+
+    // Array Debug: Add elements as returned by getter
+    (@array @[debug $builder:ident $this:tt] [$count:expr] $getter:ident) => {
+        $builder.field(::core::stringify!($getter), &$crate::DebugArray($count, |index| $this.$getter(index)));
+    };
+
+    // Array Defmt: Disabled
+    (@array @[defmt $formatter:ident $this:tt] [$count:expr] _) => {};
+
+    // Array Defmt: Write elements as returned by getter
+    (@array @[defmt $formatter:ident $this:tt] [$count:expr] $getter:ident) => {
+        $crate::defmt::write!($formatter, ", {=str}: {}", ::core::stringify!($getter), $crate::DebugArray($count, |index| $this.$getter(index)));
+    };
+
+    // Array uDebug: Disabled
+    (@array @[udebug $builder:ident $this:tt] [$count:expr] _) => {};
+
+    // Array uDebug: Add elements as returned by getter
+    (@array @[udebug $builder:ident $this:tt] [$count:expr] $getter:ident) => {
+        $builder.field(::core::stringify!($getter), &$crate::DebugArray($count, |index| $this.$getter(index)))?;
+    };
+
+    // Array Serialize: Disabled
+    (@array @[serialize $map:ident $this:tt] [$count:expr] $type:ty, $from:tt, $into:tt, _, $setter:ident) => {};
+
+    // Array Serialize: Add converted elements as sequence, failing if any conversion fails
+    (@array @[serialize $map:ident $this:tt] [$count:expr] $type:ty, $from:tt, (try $into:ty), $getter:ident, $setter:tt) => {
+        $crate::serde::ser::SerializeMap::serialize_entry(&mut $map, ::core::stringify!($getter), &$crate::serde::ArrayElements($count, |index| {
+            $this.$getter(index).map_err(|_| ::core::concat!("field `", ::core::stringify!($getter), "` holds an invalid value"))
+        }))?;
+    };
+
+    // Array Serialize: Add elements as sequence as returned by getter
+    (@array @[serialize $map:ident $this:tt] [$count:expr] $type:ty, $from:tt, $into:tt, $getter:ident, $setter:tt) => {
+        $crate::serde::ser::SerializeMap::serialize_entry(&mut $map, ::core::stringify!($getter), &$crate::serde::ArrayElements($count, |index| {
+            Ok::<_, &'static str>($this.$getter(index))
+        }))?;
+    };
+
+    // Array Deserialize: Ignore elements of field array without setter
+    (@array @[deserialize $map:ident $this:ident $key:ident] [$($context:tt)*] $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        if $key == ::core::stringify!($getter) {
+            $crate::serde::de::MapAccess::next_value::<$crate::serde::de::IgnoredAny>(&mut $map)?;
+            continue;
+        }
+    };
+
+    // Array Deserialize: Apply elements to bit ranges, failing if any element does not fit
+    (@array @[deserialize $map:ident $this:ident $key:ident] [$order:ident, $storage:ty, $count:expr, $stride:expr] $type:ty, $from:tt, $into:tt, $getter:tt, $setter:ident: $msb:expr, $lsb:expr) => {
+        if $key == $crate::bitfield!(@name $getter, $setter) {
+            let seed = $crate::serde::ArraySeed::new($crate::bitfield!(@name $getter, $setter), $count, |index, element: $crate::bitfield!(@value_type $type, $from)| {
+                let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+                let raw_value: $type = $crate::bitfield!(@serde_from $type, $from, element);
+                $crate::ops::try_set_bits(&mut $this.0, msb, lsb, raw_value)
+            });
+            $crate::serde::de::MapAccess::next_value_seed(&mut $map, seed)?;
+            continue;
+        }
+    };
+
+    // Array Deserialize: Apply elements to single bits, failing if any conversion fails
+    (@array @[deserialize $map:ident $this:ident $key:ident] [$order:ident, $storage:ty, $count:expr, $stride:expr] $type:ty, $from:tt, $into:tt, $getter:tt, $setter:ident: $bit:expr) => {
+        if $key == $crate::bitfield!(@name $getter, $setter) {
+            let seed = $crate::serde::ArraySeed::new($crate::bitfield!(@name $getter, $setter), $count, |index, element: $crate::bitfield!(@value_type $type, $from)| {
+                let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+                let raw_value: $type = $crate::bitfield!(@serde_from $type, $from, element);
+                $crate::ops::try_set_bit(&mut $this.0, bit, raw_value)
+            });
+            $crate::serde::de::MapAccess::next_value_seed(&mut $map, seed)?;
+            continue;
+        }
+    };
+
+    // Array Getter: Disabled
+    (@array @getter [$($context:tt)*] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Array Getter: Bit Range (without conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, _, $getter:ident, $setter:tt: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self, index: usize) -> $type {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+            let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+            $crate::ops::bits(&self.0, msb, lsb)
+        }
+    };
+
+    // Array Getter: Bit Range (with fallible conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, $setter:tt: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self, index: usize) -> ::core::result::Result<$into, <$into as ::core::convert::TryFrom<$type>>::Error>
+                where [$into: ::core::convert::TryFrom<$type>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+                let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+                let raw_value: $type = $crate::ops::bits(&self.0, msb, lsb);
+                <$into>::try_from(raw_value)
+            }
+        }
+    };
+
+    // Array Getter: Single Bit (with fallible conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, $setter:tt: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self, index: usize) -> ::core::result::Result<$into, <$into as ::core::convert::TryFrom<$type>>::Error>
+                where [$into: ::core::convert::TryFrom<$type>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+                let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+                let raw_value: $type = $crate::ops::bit(&self.0, bit);
+                <$into>::try_from(raw_value)
+            }
+        }
+    };
+
+    // Array Getter: Bit Range (with nested bitfield)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (nested $nested:path), $getter:ident, $setter:tt: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self, index: usize) -> $nested {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+            let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+            let raw_value: $type = $crate::ops::bits(&self.0, msb, lsb);
+            $nested(raw_value)
+        }
+    };
+
+    // Array Getter: Bit Range (with conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:ty, $getter:ident, $setter:tt: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self, index: usize) -> $into
+                where [$into: ::core::convert::From<$type>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+                let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+                let raw_value: $type = $crate::ops::bits(&self.0, msb, lsb);
+                let value: $into = <$into>::from(raw_value);
+                value
+            }
+        }
+    };
+
+    // Array Getter: Single Bit (without conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, _, $getter:ident, $setter:tt: $bit:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self, index: usize) -> bool {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+            let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+            $crate::ops::bit(&self.0, bit)
+        }
+    };
+
+    // Array Getter: Single Bit (with conversion)
+    (@array @getter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:ty, $getter:ident, $setter:tt: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self, index: usize) -> $into
+                where [$into: ::core::convert::From<$type>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($getter), "` out of bounds"));
+                let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+                let raw_value: $type = $crate::ops::bit(&self.0, bit);
+                let value: $into = <$into>::from(raw_value);
+                value
+            }
+        }
+    };
+
+    // Array Setter: Disabled
+    (@array @setter [$($context:tt)*] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {};
+
+    // Array Setter: Bit Range (without conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, _, $into:tt, $getter:tt, $setter:ident: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, index: usize, value: $type) -> &mut Self {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+            let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+            $crate::ops::set_bits(&mut self.0, msb, lsb, value);
+            self
+        }
+    };
+
+    // Array Setter: Bit Range (with fallible conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, $getter:tt, $setter:ident: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, index: usize, value: $from) -> ::core::result::Result<&mut Self, <$type as ::core::convert::TryFrom<$from>>::Error>
+                where [$type: ::core::convert::TryFrom<$from>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+                let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+                match <$type>::try_from(value) {
+                    Ok(raw_value) => {
+                        $crate::ops::set_bits(&mut self.0, msb, lsb, raw_value);
+                        Ok(self)
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+
+    // Array Setter: Single Bit (with fallible conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, $getter:tt, $setter:ident: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, index: usize, value: $from) -> ::core::result::Result<&mut Self, <$type as ::core::convert::TryFrom<$from>>::Error>
+                where [$type: ::core::convert::TryFrom<$from>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+                let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+                match <$type>::try_from(value) {
+                    Ok(raw_value) => {
+                        $crate::ops::set_bit(&mut self.0, bit, raw_value);
+                        Ok(self)
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+
+    // Array Setter: Bit Range (with nested bitfield)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, (nested $nested:path), $into:tt, $getter:tt, $setter:ident: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, index: usize, value: $nested) -> &mut Self {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+            let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+            let raw_value: $type = value.0;
+            $crate::ops::set_bits(&mut self.0, msb, lsb, raw_value);
+            self
+        }
+    };
+
+    // Array Setter: Bit Range (with conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:tt, $getter:tt, $setter:ident: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, index: usize, value: $from) -> &mut Self
+                where [$type: ::core::convert::From<$from>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+                let (msb, lsb) = $crate::bitfield!(@element $order, $storage, $stride, index, $msb, $lsb);
+                let raw_value: $type = <$type>::from(value);
+                $crate::ops::set_bits(&mut self.0, msb, lsb, raw_value);
+                self
+            }
+        }
+    };
+
+    // Array Setter: Single Bit (without conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, _, $into:tt, $getter:tt, $setter:ident: $bit:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, index: usize, value: $type) -> &mut Self {
+            assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+            let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+            $crate::ops::set_bit(&mut self.0, bit, value);
+            self
+        }
+    };
+
+    // Array Setter: Single Bit (with conversion)
+    (@array @setter [$order:ident, $storage:ty, $count:expr, $stride:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:tt, $getter:tt, $setter:ident: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, index: usize, value: $from) -> &mut Self
+                where [$type: ::core::convert::From<$from>]
+            {
+                assert!(index < $count, concat!("index of field `", ::core::stringify!($setter), "` out of bounds"));
+                let (bit, _) = $crate::bitfield!(@element $order, $storage, $stride, index, $bit);
+                let raw_value: $type = <$type>::from(value);
+                $crate::ops::set_bit(&mut self.0, bit, raw_value);
+                self
+            }
+        }
+    };
+
+    // Field: Ignore fields when processing directives
+    (@field @[impl $($context:tt)*] $($rest:tt)*) => {};
+
+    // Field: Ignore fields when searching for reserved bits
+    (@field @[reserved $($context:tt)*] $($rest:tt)*) => {};
+
+    // Field Reset: Apply default value of bit range within constructor
+    (@field @[reset $this:tt, $default:expr] $type:ty, $from:tt, $getter:tt, $setter:tt: $msb:expr, $lsb:expr) => {
+        let raw_value: $type = $crate::bitfield!(@default $type, $from, $default, [$getter, $setter]);
+        $crate::ops::set_bits(&mut $this.0, $msb, $lsb, raw_value);
+    };
+
+    // Field Reset: Apply default value of single bit within constructor
+    (@field @[reset $this:tt, $default:expr] $type:ty, $from:tt, $getter:tt, $setter:tt: $bit:expr) => {
+        let raw_value: $type = $crate::bitfield!(@default $type, $from, $default, [$getter, $setter]);
+        $crate::ops::set_bit(&mut $this.0, $bit, raw_value);
+    };
+
+    // Field Reset: Skip reset method for fields without setter
+    (@field @[resets $default:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, _: $($exprs:expr),*) => {};
+
+    // Field Reset: Name reset method after setter for fields without getter
+    (@field @[resets $default:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[resets $default, $setter] $(#[$attributes])* $visibility $type, $from, $into, _, $setter: $($exprs),*}
+    };
+
+    // Field Reset: Name reset method after getter
+    (@field @[resets $default:expr] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[resets $default, $getter] $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*}
+    };
+
+    // Field Reset: Bit Range
+    (@field @[resets $default:expr, $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $msb:expr, $lsb:expr) => {
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<reset_ $field>](&mut self) -> &mut Self {
+                let raw_value: $type = $crate::bitfield!(@default $type, $from, $default, [$getter, $setter]);
+                $crate::ops::set_bits(&mut self.0, $msb, $lsb, raw_value);
+                self
+            }
+        }
+    };
+
+    // Field Reset: Single Bit
+    (@field @[resets $default:expr, $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $bit:expr) => {
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<reset_ $field>](&mut self) -> &mut Self {
+                let raw_value: $type = $crate::bitfield!(@default $type, $from, $default, [$getter, $setter]);
+                $crate::ops::set_bit(&mut self.0, $bit, raw_value);
+                self
+            }
+        }
+    };
+
+    // Field: Ignore fields without default value when processing defaults
+    (@field @[defaults $($context:tt)*] $($rest:tt)*) => {};
+    (@field @[reset $this:tt] $($rest:tt)*) => {};
+    (@field @resets $($rest:tt)*) => {};
+
+    // Field With: Disabled
+    (@field @with $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, _: $($exprs:expr),*) => {};
+
+    // Field With: Name builder after setter for fields without getter
+    (@field @with $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[with $setter] $(#[$attributes])* $visibility $type, $from, $into, _, $setter: $($exprs),*}
+    };
+
+    // Field With: Name builder after getter
+    (@field @with $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[with $getter] $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*}
+    };
+
+    // Field With: Without conversion
+    (@field @[with $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, _, $into:tt, $getter:tt, $setter:ident: $($exprs:expr),*) => {
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<with_ $field>](mut self, value: $type) -> Self {
+                self.$setter(value);
+                self
+            }
+        }
+    };
+
+    // Field With: With fallible conversion
+    (@field @[with $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, $getter:tt, $setter:ident: $($exprs:expr),*) => {
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<with_ $field>](mut self, value: $from) -> ::core::result::Result<Self, <$type as ::core::convert::TryFrom<$from>>::Error>
+                    where [$type: ::core::convert::TryFrom<$from>]
+                {
+                    match self.$setter(value) {
+                        Ok(_) => {}
+                        Err(error) => return Err(error),
+                    }
+                    Ok(self)
+                }
+            }
+        }
+    };
+
+    // Field With: With nested bitfield
+    (@field @[with $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, (nested $nested:path), $into:tt, $getter:tt, $setter:ident: $($exprs:expr),*) => {
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<with_ $field>](mut self, value: $nested) -> Self {
+                self.$setter(value);
+                self
+            }
+        }
+    };
+
+    // Field With: With conversion
+    (@field @[with $field:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:tt, $getter:tt, $setter:ident: $($exprs:expr),*) => {
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<with_ $field>](mut self, value: $from) -> Self
+                    where [$type: ::core::convert::From<$from>]
+                {
+                    self.$setter(value);
+                    self
+                }
+            }
+        }
+    };
+
+    // Field Consts: Name constants after setter for fields without getter
+    (@field @[consts $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[consts $storage, $setter] $visibility, $($exprs),*}
+    };
+
+    // Field Consts: Name constants after getter
+    (@field @[consts $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:tt: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @[consts $storage, $getter] $visibility, $($exprs),*}
+    };
+
+    // Field Consts: Bit Range
+    (@field @[consts $storage:ty, $field:ident] $visibility:vis, $msb:expr, $lsb:expr) => {
+        $crate::paste::paste! {
+            #[doc = ::core::concat!("Bit mask of field `", ::core::stringify!($field), "` within the storage.")]
+            $visibility const [<$field:upper _MASK>]: $storage = {
+                let mut mask = <$storage as $crate::BitStorage>::ZERO;
+                $crate::ops::set_bits(&mut mask, $msb, $lsb, u128::MAX >> (127 - ($msb - $lsb)));
+                mask
+            };
+
+            #[doc = ::core::concat!("Position of the least significant bit of field `", ::core::stringify!($field), "`.")]
+            $visibility const [<$field:upper _SHIFT>]: usize = $lsb;
+
+            #[doc = ::core::concat!("Number of bits of field `", ::core::stringify!($field), "`.")]
+            $visibility const [<$field:upper _WIDTH>]: usize = $msb - $lsb + 1;
+        }
+    };
+
+    // Field Consts: Single Bit
+    (@field @[consts $storage:ty, $field:ident] $visibility:vis, $bit:expr) => {
+        $crate::bitfield! {@field @[consts $storage, $field] $visibility, $bit, $bit}
+    };
+
+    // Field Serde Len: Disabled
+    (@field @[serde_len $len:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field Serde Len: Count field with getter
+    (@field @[serde_len $len:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:tt: $($exprs:expr),*) => {
+        $len += 1;
+    };
+
+    // Field Serialize: Disabled
+    (@field @[serialize $map:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field Serialize: Add converted field value, failing if the conversion fails
+    (@field @[serialize $map:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, $setter:tt: $($exprs:expr),*) => {
+        match $this.$getter() {
+            Ok(value) => $crate::serde::ser::SerializeMap::serialize_entry(&mut $map, ::core::stringify!($getter), &value)?,
+            Err(_) => return Err($crate::serde::ser::Error::custom(
+                ::core::concat!("field `", ::core::stringify!($getter), "` holds an invalid value"),
+            )),
+        }
+    };
+
+    // Field Serialize: Add field value as returned by getter
+    (@field @[serialize $map:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:tt: $($exprs:expr),*) => {
+        $crate::serde::ser::SerializeMap::serialize_entry(&mut $map, ::core::stringify!($getter), &$this.$getter())?;
+    };
+
+    // Field Serde Known: Accept name of field
+    (@field @[serde_known $key:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $($exprs:expr),*) => {
+        if $key == $crate::bitfield!(@name $getter, $setter) {
+            return Some($crate::bitfield!(@name $getter, $setter));
+        }
+    };
+
+    // Field Deserialize: Ignore value of field without setter
+    (@field @[deserialize $map:ident $this:ident $key:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        if $key == ::core::stringify!($getter) {
+            $crate::serde::de::MapAccess::next_value::<$crate::serde::de::IgnoredAny>(&mut $map)?;
+            continue;
+        }
+    };
+
+    // Field Deserialize: Apply value using fallible setter, failing if the value does not fit
+    (@field @[deserialize $map:ident $this:ident $key:ident] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:ident: $($exprs:expr),*) => {
+        if $key == $crate::bitfield!(@name $getter, $setter) {
+            let value: $crate::bitfield!(@value_type $type, $from) = $crate::serde::de::MapAccess::next_value(&mut $map)?;
+            $crate::paste::paste! {
+                if let Err(error) = $this.[<try_ $setter>](value) {
+                    return Err($crate::serde::de::Error::custom(::core::format_args!(
+                        "invalid value for field `{}`: {}",
+                        $crate::bitfield!(@name $getter, $setter),
+                        error,
+                    )));
+                }
+            }
+            continue;
+        }
+    };
+
+    // Field: Propagate field with getter and setter to individual macros
+    (@field @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:ident: $($exprs:expr),*) => {
+        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, _: $($exprs),*}
+        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, _, $setter: $($exprs),*}
+    };
+
+    // Field Debug: Disabled
+    (@field @[debug $builder:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field Debug: Add field value as returned by getter
+    (@field @[debug $builder:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        $builder.field(::core::stringify!($getter), &$this.$getter());
+    };
+
+    // Field Defmt: Disabled
+    (@field @[defmt $formatter:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field Defmt: Write field value as returned by getter
+    (@field @[defmt $formatter:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        $crate::defmt::write!($formatter, ", {=str}: {}", ::core::stringify!($getter), $this.$getter());
+    };
+
+    // Field uDebug: Disabled
+    (@field @[udebug $builder:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field uDebug: Add field value as returned by getter
+    (@field @[udebug $builder:ident $this:tt] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        $builder.field(::core::stringify!($getter), &$this.$getter())?;
+    };
+
+    // Field Getter: Disabled
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Field Getter: Bit Range (without conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, $getter:ident, _: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self) -> $type {
+            $crate::ops::bits(&self.0, $msb, $lsb)
+        }
+    };
+
+    // Field Getter: Bit Range (with fallible conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, _: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self) -> ::core::result::Result<$into, <$into as ::core::convert::TryFrom<$type>>::Error>
+                where [$into: ::core::convert::TryFrom<$type>]
+            {
+                let raw_value: $type = $crate::ops::bits(&self.0, $msb, $lsb);
+                <$into>::try_from(raw_value)
+            }
+        }
+    };
+
+    // Field Getter: Single Bit (with fallible conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, _: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self) -> ::core::result::Result<$into, <$into as ::core::convert::TryFrom<$type>>::Error>
+                where [$into: ::core::convert::TryFrom<$type>]
+            {
+                let raw_value: $type = $crate::ops::bit(&self.0, $bit);
+                <$into>::try_from(raw_value)
+            }
+        }
+    };
+
+    // Field Getter: Bit Range (with nested bitfield)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (nested $nested:path), $getter:ident, _: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self) -> $nested {
+            let raw_value: $type = $crate::ops::bits(&self.0, $msb, $lsb);
+            $nested(raw_value)
+        }
+    };
+
+    // Field Getter: Bit Range (with conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:ty, $getter:ident, _: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self) -> $into
+                where [$into: ::core::convert::From<$type>]
+            {
+                let raw_value: $type = $crate::ops::bits(&self.0, $msb, $lsb);
+                let value: $into = <$into>::from(raw_value);
+                value
+            }
+        }
+    };
+
+    // Field Getter: Single Bit (without conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, $getter:ident, _: $bit:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $getter(&self) -> bool {
+            $crate::ops::bit(&self.0, $bit)
+        }
+    };
+
+    // Field Getter: Single Bit (with conversion)
+    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:ty, $getter:ident, _: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $getter(&self) -> $into
+                where [$into: ::core::convert::From<$type>]
+            {
+                let raw_value: $type = $crate::ops::bit(&self.0, $bit);
+                let value: $into = <$into>::from(raw_value);
+                value
+            }
+        }
+    };
+
+    // Field Setter: Disabled
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {};
+
+    // Field Setter: Bit Range (without conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, _, $setter:ident: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, value: $type) -> &mut Self {
+            $crate::ops::set_bits(&mut self.0, $msb, $lsb, value);
+            self
+        }
+
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<try_ $setter>](&mut self, value: $type) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                match $crate::ops::try_set_bits(&mut self.0, $msb, $lsb, value) {
+                    Ok(()) => Ok(self),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+
+    // Field Setter: Bit Range (with fallible conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, _, $setter:ident: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, value: $from) -> ::core::result::Result<&mut Self, <$type as ::core::convert::TryFrom<$from>>::Error>
+                where [$type: ::core::convert::TryFrom<$from>]
+            {
+                match <$type>::try_from(value) {
+                    Ok(raw_value) => {
+                        $crate::ops::set_bits(&mut self.0, $msb, $lsb, raw_value);
+                        Ok(self)
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+        }
+
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<try_ $setter>](&mut self, value: $from) -> ::core::result::Result<&mut Self, $crate::BitfieldError>
+                    where [$type: ::core::convert::TryFrom<$from>]
+                {
+                    match <$type>::try_from(value) {
+                        Ok(raw_value) => match $crate::ops::try_set_bits(&mut self.0, $msb, $lsb, raw_value) {
+                            Ok(()) => Ok(self),
+                            Err(error) => Err(error),
+                        },
+                        Err(_) => Err($crate::BitfieldError::InvalidValue),
+                    }
+                }
+            }
+        }
+    };
+
+    // Field Setter: Single Bit (with fallible conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, _, $setter:ident: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, value: $from) -> ::core::result::Result<&mut Self, <$type as ::core::convert::TryFrom<$from>>::Error>
+                where [$type: ::core::convert::TryFrom<$from>]
+            {
+                match <$type>::try_from(value) {
+                    Ok(raw_value) => {
+                        $crate::ops::set_bit(&mut self.0, $bit, raw_value);
+                        Ok(self)
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+        }
+
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<try_ $setter>](&mut self, value: $from) -> ::core::result::Result<&mut Self, $crate::BitfieldError>
+                    where [$type: ::core::convert::TryFrom<$from>]
+                {
+                    match <$type>::try_from(value) {
+                        Ok(raw_value) => match $crate::ops::try_set_bit(&mut self.0, $bit, raw_value) {
+                            Ok(()) => Ok(self),
+                            Err(error) => Err(error),
+                        },
+                        Err(_) => Err($crate::BitfieldError::InvalidValue),
+                    }
+                }
+            }
+        }
+    };
+
+    // Field Setter: Bit Range (with nested bitfield)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, (nested $nested:path), $into:tt, _, $setter:ident: $msb:expr, $lsb:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, value: $nested) -> &mut Self {
+            let raw_value: $type = value.0;
+            $crate::ops::set_bits(&mut self.0, $msb, $lsb, raw_value);
+            self
+        }
+
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<try_ $setter>](&mut self, value: $nested) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                let raw_value: $type = value.0;
+                match $crate::ops::try_set_bits(&mut self.0, $msb, $lsb, raw_value) {
+                    Ok(()) => Ok(self),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+
+    // Field Setter: Bit Range (with conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:tt, _, $setter:ident: $msb:expr, $lsb:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, value: $from) -> &mut Self
+                where [$type: ::core::convert::From<$from>]
+            {
+                let raw_value: $type = <$type>::from(value);
+                $crate::ops::set_bits(&mut self.0, $msb, $lsb, raw_value);
+                self
+            }
+        }
+
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<try_ $setter>](&mut self, value: $from) -> ::core::result::Result<&mut Self, $crate::BitfieldError>
+                    where [$type: ::core::convert::From<$from>]
+                {
+                    let raw_value: $type = <$type>::from(value);
+                    match $crate::ops::try_set_bits(&mut self.0, $msb, $lsb, raw_value) {
+                        Ok(()) => Ok(self),
+                        Err(error) => Err(error),
+                    }
+                }
+            }
+        }
+    };
+
+    // Field Setter: Single Bit (without conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, _, $setter:ident: $bit:expr) => {
+        $(#[$attributes])*
+        $visibility const fn $setter(&mut self, value: $type) -> &mut Self {
+            $crate::ops::set_bit(&mut self.0, $bit, value);
+            self
+        }
+
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility const fn [<try_ $setter>](&mut self, value: $type) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                match $crate::ops::try_set_bit(&mut self.0, $bit, value) {
+                    Ok(()) => Ok(self),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+
+    // Field Setter: Single Bit (with conversion)
+    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:tt, _, $setter:ident: $bit:expr) => {
+        $crate::__bitfield_fn! {
+            $(#[$attributes])*
+            $visibility fn $setter(&mut self, value: $from) -> &mut Self
+                where [$type: ::core::convert::From<$from>]
+            {
+                let raw_value: $type = <$type>::from(value);
+                $crate::ops::set_bit(&mut self.0, $bit, raw_value);
+                self
+            }
+        }
+
+        $crate::paste::paste! {
+            $crate::__bitfield_fn! {
+                $(#[$attributes])*
+                $visibility fn [<try_ $setter>](&mut self, value: $from) -> ::core::result::Result<&mut Self, $crate::BitfieldError>
+                    where [$type: ::core::convert::From<$from>]
+                {
+                    let raw_value: $type = <$type>::from(value);
+                    match $crate::ops::try_set_bit(&mut self.0, $bit, raw_value) {
+                        Ok(()) => Ok(self),
+                        Err(error) => Err(error),
+                    }
+                }
+            }
+        }
+    };
+
+    // Atomic Getter: Disabled
+    (@field @[atomic_getter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+
+    // Atomic Getter: With fallible conversion
+    (@field @[atomic_getter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, (try $into:ty), $getter:ident, _: $($exprs:expr),*) => {
+        $(#[$attributes])*
+        $visibility fn $getter(&self, ordering: ::core::sync::atomic::Ordering) -> ::core::result::Result<$into, <$into as ::core::convert::TryFrom<$type>>::Error> {
+            let raw: $storage = self.0.load(ordering);
+            let raw_value: $type = $crate::bitfield!(@atomic_read raw, $($exprs),*);
+            <$into>::try_from(raw_value)
+        }
+    };
+
+    // Atomic Getter: Without conversion, with nested bitfield or with conversion
+    (@field @[atomic_getter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {
+        $(#[$attributes])*
+        $visibility fn $getter(&self, ordering: ::core::sync::atomic::Ordering) -> $crate::bitfield!(@value_type $type, $into) {
+            let raw: $storage = self.0.load(ordering);
+            let raw_value: $type = $crate::bitfield!(@atomic_read raw, $($exprs),*);
+            $crate::bitfield!(@atomic_into $type, $into, raw_value)
+        }
+    };
+
+    // Atomic Setter: Disabled
+    (@field @[atomic_setter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {};
+
+    // Atomic Setter: With fallible conversion
+    (@field @[atomic_setter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, (try $from:ty), $into:tt, _, $setter:ident: $($exprs:expr),*) => {
+        $(#[$attributes])*
+        $visibility fn $setter(&self, value: $from, ordering: ::core::sync::atomic::Ordering) -> ::core::result::Result<&Self, <$type as ::core::convert::TryFrom<$from>>::Error> {
+            let raw_value: $type = <$type>::try_from(value)?;
+            $crate::bitfield! {@atomic_write self, $storage, raw_value, ordering, $($exprs),*}
+            Ok(self)
+        }
+
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility fn [<try_ $setter>](&self, value: $from, ordering: ::core::sync::atomic::Ordering) -> ::core::result::Result<&Self, $crate::BitfieldError> {
+                let raw_value: $type = match <$type>::try_from(value) {
+                    Ok(raw_value) => raw_value,
+                    Err(_) => return Err($crate::BitfieldError::InvalidValue),
+                };
+                $crate::bitfield! {@atomic_check $storage, raw_value, $($exprs),*}
+                $crate::bitfield! {@atomic_write self, $storage, raw_value, ordering, $($exprs),*}
+                Ok(self)
+            }
+        }
+    };
+
+    // Atomic Setter: Without conversion, with nested bitfield or with conversion
+    (@field @[atomic_setter $storage:ty] $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {
+        $(#[$attributes])*
+        $visibility fn $setter(&self, value: $crate::bitfield!(@value_type $type, $from), ordering: ::core::sync::atomic::Ordering) -> &Self {
+            let raw_value: $type = $crate::bitfield!(@atomic_from $type, $from, value);
+            $crate::bitfield! {@atomic_write self, $storage, raw_value, ordering, $($exprs),*}
+            self
+        }
+
+        $crate::paste::paste! {
+            $(#[$attributes])*
+            $visibility fn [<try_ $setter>](&self, value: $crate::bitfield!(@value_type $type, $from), ordering: ::core::sync::atomic::Ordering) -> ::core::result::Result<&Self, $crate::BitfieldError> {
+                let raw_value: $type = $crate::bitfield!(@atomic_from $type, $from, value);
+                $crate::bitfield! {@atomic_check $storage, raw_value, $($exprs),*}
+                $crate::bitfield! {@atomic_write self, $storage, raw_value, ordering, $($exprs),*}
+                Ok(self)
+            }
+        }
+    };
+
+    // Value Type: Value type of a field without conversion
+    (@value_type $type:ty, _) => {
+        $type
+    };
+
+    // Value Type: Value type of a field with nested bitfield
+    (@value_type $type:ty, (nested $nested:path)) => {
+        $nested
+    };
+
+    // Value Type: Value type of a field with fallible conversion
+    (@value_type $type:ty, (try $convert:ty)) => {
+        $convert
+    };
+
+    // Value Type: Value type of a field with conversion
+    (@value_type $type:ty, $convert:ty) => {
+        $convert
+    };
+
+    // Atomic Into: Raw value without conversion
+    (@atomic_into $type:ty, _, $raw_value:ident) => {
+        $raw_value
+    };
+
+    // Atomic Into: Raw value wrapped into nested bitfield
+    (@atomic_into $type:ty, (nested $nested:path), $raw_value:ident) => {
+        $nested($raw_value)
+    };
+
+    // Atomic Into: Raw value with conversion
+    (@atomic_into $type:ty, $into:ty, $raw_value:ident) => {
+        <$into>::from($raw_value)
+    };
+
+    // Atomic From: Raw value without conversion
+    (@atomic_from $type:ty, _, $value:ident) => {
+        $value
+    };
+
+    // Atomic From: Raw value of nested bitfield
+    (@atomic_from $type:ty, (nested $nested:path), $value:ident) => {
+        $value.0
+    };
+
+    // Atomic From: Raw value with conversion
+    (@atomic_from $type:ty, $from:ty, $value:ident) => {
+        <$type>::from($value)
+    };
+
+    // Serde From: Raw value with fallible conversion, returning early if the conversion fails
+    (@serde_from $type:ty, (try $from:ty), $value:ident) => {
+        match <$type>::try_from($value) {
+            Ok(raw_value) => raw_value,
+            Err(_) => return Err($crate::BitfieldError::InvalidValue),
+        }
+    };
+
+    // Serde From: Raw value as for atomic setters
+    (@serde_from $type:ty, $from:tt, $value:ident) => {
+        $crate::bitfield!(@atomic_from $type, $from, $value)
+    };
+
+    // Atomic Read: Bit Range of loaded raw value
+    (@atomic_read $raw:ident, $msb:expr, $lsb:expr) => {
+        $crate::ops::bits(&$raw, $msb, $lsb)
+    };
+
+    // Atomic Read: Single Bit of loaded raw value
+    (@atomic_read $raw:ident, $bit:expr) => {
+        $crate::ops::bit(&$raw, $bit)
+    };
+
+    // Atomic Check: Return early if the value does not fit into the bit range
+    (@atomic_check $storage:ty, $raw_value:ident, $msb:expr, $lsb:expr) => {
+        let mut scratch = <$storage as $crate::BitStorage>::ZERO;
+        $crate::ops::try_set_bits(&mut scratch, $msb, $lsb, $raw_value)?;
+    };
+
+    // Atomic Check: Return early if the single bit is out of bounds
+    (@atomic_check $storage:ty, $raw_value:ident, $bit:expr) => {
+        let mut scratch = <$storage as $crate::BitStorage>::ZERO;
+        $crate::ops::try_set_bit(&mut scratch, $bit, $raw_value)?;
+    };
+
+    // Atomic Write: Bit Range using a compare-exchange loop, retrying on concurrent modification
+    (@atomic_write $this:tt, $storage:ty, $raw_value:ident, $ordering:ident, $msb:expr, $lsb:expr) => {
+        let mut current: $storage = $this.0.load(::core::sync::atomic::Ordering::Relaxed);
+        loop {
+            let mut new = current;
+            $crate::ops::set_bits(&mut new, $msb, $lsb, $raw_value);
+            match $this.0.compare_exchange_weak(current, new, $ordering, $crate::failure_ordering($ordering)) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+    };
+
+    // Atomic Write: Single Bit using fetch_or or fetch_and
+    (@atomic_write $this:tt, $storage:ty, $raw_value:ident, $ordering:ident, $bit:expr) => {
+        let mut mask = <$storage as $crate::BitStorage>::ZERO;
+        $crate::ops::set_bit(&mut mask, $bit, true);
+        if $raw_value {
+            $this.0.fetch_or(mask, $ordering);
+        } else {
+            $this.0.fetch_and(!mask, $ordering);
+        }
+    };
+}
+
+/// An error returned by the fallible `try_` variants of all bit operations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BitfieldError {
+    /// The least significant bit is out of bounds for the storage type.
+    LsbOutOfBounds,
+    /// The most significant bit is out of bounds for the storage type.
+    MsbOutOfBounds,
+    /// The least significant bit is greater than the most significant bit.
+    InvertedRange,
+    /// The value does not fit into the bit range without being truncated.
+    ValueTruncated,
+    /// The value could not be converted into the raw type of the bit range.
+    InvalidValue,
+    /// The bitfield has no field with the given name supporting the requested access.
+    UnknownField,
+}
+
+impl BitfieldError {
+    /// Get a static description of the error, usable within `const fn`.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            BitfieldError::LsbOutOfBounds => "lsb is out of bounds for bit range",
+            BitfieldError::MsbOutOfBounds => "msb is out of bounds for bit range",
+            BitfieldError::InvertedRange => "lsb must not be greater than msb for bit range",
+            BitfieldError::ValueTruncated => "value truncated in bit range operation",
+            BitfieldError::InvalidValue => "value conversion failed in bit range operation",
+            BitfieldError::UnknownField => "no field with the given name supports the requested access",
+        }
+    }
+}
+
+impl core::fmt::Display for BitfieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// Metadata of a single field, as listed within the `FIELDS` constant of every bitfield.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldInfo {
+    /// Name of the field, which is the getter or the setter if the field has no getter.
+    pub name: &'static str,
+    /// Most significant bit of the field within the storage, counted from the lsb.
+    pub msb: usize,
+    /// Least significant bit of the field within the storage, counted from the lsb.
+    pub lsb: usize,
+    /// Whether the field can be read, written or both.
+    pub access: FieldAccess,
+    /// Name of the raw type of the field, as written within the macro invocation.
+    pub raw_type: &'static str,
+    /// Elements of a field array, with `msb` and `lsb` describing the first element, or `None` for
+    /// regular fields.
+    pub array: Option<FieldArray>,
+}
+
+impl FieldInfo {
+    /// Get the bit range of the element with the given index as inclusive (msb, lsb) tuple, counted
+    /// from the lsb, or `None` if out of bounds. Regular fields consist of a single element.
+    pub const fn element(&self, index: usize) -> Option<(usize, usize)> {
+        match self.array {
+            Some(array) if index < array.count => {
+                let offset = array.step * index as isize;
+                Some((
+                    (self.msb as isize).wrapping_add(offset) as usize,
+                    (self.lsb as isize).wrapping_add(offset) as usize,
+                ))
+            }
+            None if index == 0 => Some((self.msb, self.lsb)),
+            _ => None,
+        }
+    }
+
+    /// Check whether every element of the given fields lies within the given number of bits, as
+    /// required for nesting a bitfield with these fields into a field of that width.
+    pub const fn fit(fields: &[FieldInfo], bits: usize) -> bool {
+        let mut index = 0;
+        while index < fields.len() {
+            let field = &fields[index];
+            let last = match field.array {
+                Some(array) => array.count.saturating_sub(1),
+                None => 0,
+            };
+
+            // elements are evenly spaced, so the first and last element enclose all others
+            match (field.element(0), field.element(last)) {
+                (Some((first, _)), Some((last, _))) if first < bits && last < bits => {}
+                _ => return false,
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Find the field with the given name along with its bit range, addressing elements of field
+    /// arrays as `name[index]`.
+    pub fn find(fields: &'static [FieldInfo], name: &str) -> Option<(&'static FieldInfo, usize, usize)> {
+        let (name, index) = match name.strip_suffix(']').and_then(|name| name.split_once('[')) {
+            Some((name, index)) => (name, Some(index.parse::<usize>().ok()?)),
+            None => (name, None),
+        };
+
+        let field = fields.iter().find(|field| field.name == name && field.array.is_some() == index.is_some())?;
+        let (msb, lsb) = field.element(index.unwrap_or(0))?;
+        Some((field, msb, lsb))
+    }
+}
+
+/// Elements of a field array, as listed within the `FIELDS` constant of every bitfield.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldArray {
+    /// Number of elements.
+    pub count: usize,
+    /// Offset between the bit ranges of two consecutive elements, counted from the lsb.
+    pub step: isize,
+}
+
+/// Access to a field, depending on whether a getter and or a setter has been declared.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FieldAccess {
+    /// The field only has a getter.
+    Read,
+    /// The field only has a setter.
+    Write,
+    /// The field has both a getter and a setter.
+    ReadWrite,
+}
+
+impl FieldAccess {
+    /// Check whether the field has a getter.
+    pub const fn is_readable(&self) -> bool {
+        matches!(self, FieldAccess::Read | FieldAccess::ReadWrite)
+    }
+
+    /// Check whether the field has a setter.
+    pub const fn is_writable(&self) -> bool {
+        matches!(self, FieldAccess::Write | FieldAccess::ReadWrite)
+    }
+}
+
+/// Register diagram of a bitfield as returned by `diagram()`, which renders the storage in rows of
+/// up to 32 bits along with the name and current raw value of every field when displayed.
+pub struct Diagram<'a, T> {
+    storage: &'a T,
+    fields: &'static [FieldInfo],
+}
+
+/// Element of a field shown within a register diagram, with the index of elements of field arrays.
+#[derive(Copy, Clone)]
+struct DiagramField {
+    info: &'static FieldInfo,
+    index: Option<usize>,
+    lsb: usize,
+}
 
-    // Impl: Implement BitRange<T> and BitRangeMut<T> for struct(pub T)
-    (@impl_range struct $name:ident($type:ty)) => {
-        impl<T> const $crate::BitRange<T> for $name
-        where
-            $type: ~const $crate::BitRange<T>
-        {
-            #[inline]
-            fn bits(&self, msb: usize, lsb: usize) -> T {
-                self.0.bits(msb, lsb)
+/// Line of a single row within a register diagram.
+#[derive(Copy, Clone)]
+enum DiagramLine {
+    Border,
+    Name,
+    Value,
+}
+
+impl<'a, T> Diagram<'a, T> {
+    /// Create a new diagram of the given storage, using the given field metadata.
+    #[doc(hidden)]
+    pub const fn new(storage: &'a T, fields: &'static [FieldInfo]) -> Self {
+        Self { storage, fields }
+    }
+
+    /// Find the first field element containing the given bit.
+    fn field(&self, bit: usize) -> Option<DiagramField> {
+        self.fields.iter().find_map(|info| {
+            let count = info.array.map_or(1, |array| array.count);
+            (0..count).find_map(|index| match info.element(index) {
+                Some((msb, lsb)) if lsb <= bit && bit <= msb => Some(DiagramField {
+                    info,
+                    index: info.array.map(|_| index),
+                    lsb,
+                }),
+                _ => None,
+            })
+        })
+    }
+
+    /// Get the lowest bit of the segment starting at `msb` within a row ending at `lsb`, which either
+    /// spans the field element containing `msb` or all following bits not contained in any field.
+    fn segment(&self, msb: usize, lsb: usize) -> (usize, Option<DiagramField>) {
+        match self.field(msb) {
+            Some(field) => (field.lsb.max(lsb), Some(field)),
+            None => {
+                let mut low = msb;
+                while low > lsb && self.field(low - 1).is_none() {
+                    low -= 1;
+                }
+                (low, None)
             }
         }
+    }
+}
 
-        impl<T> const $crate::BitRangeMut<T> for $name
-        where
-            $type: ~const $crate::BitRange<T> + ~const $crate::BitRangeMut<T>
-        {
-            #[inline]
-            fn set_bits(&mut self, msb: usize, lsb: usize, value: T) -> &mut Self {
-                self.0.set_bits(msb, lsb, value);
-                self
-            }
+impl<T: BitRange<u128>> Diagram<'_, T> {
+    /// Write a single row of the diagram, spanning the bits `lsb..=msb`.
+    fn row(&self, f: &mut core::fmt::Formatter<'_>, msb: usize, lsb: usize) -> core::fmt::Result {
+        for bit in (lsb..=msb).rev() {
+            write!(f, " {:^3}", bit)?;
         }
-    };
 
-    // Parse Fields: Process regular fields without from/into conversion
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, _, _, $getter, $setter: $($exprs),*; $($rest)*}
-    };
+        for line in [DiagramLine::Border, DiagramLine::Name, DiagramLine::Value, DiagramLine::Border] {
+            f.write_str("\n")?;
+            self.line(f, line, msb, lsb)?;
+        }
+        Ok(())
+    }
 
-    // Parse Fields: Process fields with from conversion
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from $from:ty, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $type, $getter, $setter: $($exprs),*; $($rest)*}
-    };
+    /// Write a single line of a row, with every segment being four characters wide per bit.
+    fn line(&self, f: &mut core::fmt::Formatter<'_>, line: DiagramLine, msb: usize, lsb: usize) -> core::fmt::Result {
+        let separator = if let DiagramLine::Border = line { "+" } else { "|" };
+        f.write_str(separator)?;
 
-    // Parse Fields: Process fields with into conversion
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, into $into:ty, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $type, $into, $getter, $setter: $($exprs),*; $($rest)*}
-    };
+        let mut high = msb;
+        loop {
+            let (low, field) = self.segment(high, lsb);
+            let width = (high - low + 1) * 4 - 1;
+            match line {
+                DiagramLine::Border => pad(f, '-', width)?,
+                DiagramLine::Name => {
+                    // truncate by characters, as field names are not restricted to ascii
+                    let name = field.map_or("", |field| field.info.name);
+                    let name = match name.char_indices().nth(width) {
+                        Some((end, _)) => &name[..end],
+                        None => name,
+                    };
 
-    // Parse Fields: Process fields with from and into conversion for same type
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from into $from_into:ty, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from_into, $from_into, $getter, $setter: $($exprs),*; $($rest)*}
-    };
+                    // append index of array elements if it fits, preferring the name otherwise
+                    let index = field.and_then(|field| field.index);
+                    let suffix = index.map_or(0, |index| decimal_digits(index) + 2);
+                    let index = index.filter(|_| name.chars().count() + suffix <= width);
+                    let length = name.chars().count() + if index.is_some() { suffix } else { 0 };
 
-    // Parse Fields: Process fields with from and into conversion for different types
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, from $from:ty, into $into:ty, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@fields @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*; $($rest)*}
-    };
+                    pad(f, ' ', (width - length) / 2)?;
+                    f.write_str(name)?;
+                    if let Some(index) = index {
+                        write!(f, "[{}]", index)?;
+                    }
+                    pad(f, ' ', width - length - (width - length) / 2)?;
+                }
+                DiagramLine::Value => {
+                    let value: u128 = self.storage.bits(high, low);
+                    let mut digits = 1;
+                    while digits < 32 && value >> (digits * 4) != 0 {
+                        digits += 1;
+                    }
+                    if high != low {
+                        digits += 2;
+                    }
+                    pad(f, ' ', (width - digits) / 2)?;
+                    if high == low {
+                        write!(f, "{}", value)?;
+                    } else {
+                        write!(f, "{:#x}", value)?;
+                    }
+                    pad(f, ' ', width - digits - (width - digits) / 2)?;
+                }
+            }
+            f.write_str(separator)?;
 
-    // Fields: Process each field one-by-one by splitting list head off
-    (@fields @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:tt, $setter:tt: $($exprs:expr),*; $($rest:tt)*) => {
-        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, $setter: $($exprs),*}
-        $crate::bitfield! {@fields @$variant $($rest)*}
-    };
+            if low == lsb {
+                return Ok(());
+            }
+            high = low - 1;
+        }
+    }
+}
 
-    // Fields: Stop case once all fields are processed
-    (@fields @$variant:tt) => {};
+impl<T: BitRange<u128>> core::fmt::Display for Diagram<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bits = core::mem::size_of::<T>() * 8;
+        let mut high = bits;
+        while high > 0 {
+            let low = high.saturating_sub(32);
+            if high != bits {
+                f.write_str("\n\n")?;
+            }
+            self.row(f, high - 1, low)?;
+            high = low;
+        }
+        Ok(())
+    }
+}
 
-    // Field: Propagate field with getter and setter to individual macros
-    (@field @$variant:tt $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, $setter:ident: $($exprs:expr),*) => {
-        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, $getter, _: $($exprs),*}
-        $crate::bitfield! {@field @$variant $(#[$attributes])* $visibility $type, $from, $into, _, $setter: $($exprs),*}
-    };
+/// Count the decimal digits of the given value.
+fn decimal_digits(mut value: usize) -> usize {
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
 
-    // Field Getter: Bit Range (without conversion)
-    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, $getter:ident, _: $msb:expr, $lsb:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $getter(&self) -> $type {
-            use $crate::BitRange;
-            self.bits($msb, $lsb)
-        }
-    };
+/// Write the given character repeatedly.
+fn pad(f: &mut core::fmt::Formatter<'_>, character: char, count: usize) -> core::fmt::Result {
+    for _ in 0..count {
+        core::fmt::Write::write_char(f, character)?;
+    }
+    Ok(())
+}
 
-    // Field Getter: Bit Range (with conversion)
-    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:ty, $getter:ident, _: $msb:expr, $lsb:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $getter(&self) -> $into
-            where $into: ~const ::core::convert::From<$type>
-        {
-            use $crate::BitRange;
-            let raw_value: $type = self.bits($msb, $lsb);
-            let value: $into = <$into>::from(raw_value);
-            value
-        }
-    };
+/// Guard returned by the `_mut` methods of nested bitfield fields, which dereferences to a copy of
+/// the nested bitfield and writes it back into the parent bitfield once dropped.
+pub struct NestedMut<'a, P, N> {
+    parent: &'a mut P,
+    value: Option<N>,
+    write: fn(&mut P, N),
+}
 
-    // Field Getter: Single Bit (without conversion)
-    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, $getter:ident, _: $bit:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $getter(&self) -> bool {
-            use $crate::Bit;
-            self.bit($bit)
+impl<'a, P, N> NestedMut<'a, P, N> {
+    /// Create a new guard for the given nested value, using `write` to store it into the parent.
+    #[doc(hidden)]
+    pub fn new(parent: &'a mut P, value: N, write: fn(&mut P, N)) -> Self {
+        Self {
+            parent,
+            value: Some(value),
+            write,
         }
-    };
+    }
+}
 
-    // Field Getter: Single Bit (with conversion)
-    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:ty, $getter:ident, _: $bit:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $getter(&self) -> $into
-            where $into: ~const ::core::convert::From<$type>
-        {
-            use $crate::Bit;
-            let raw_value: $type = self.bit($bit);
-            let value: $into = <$into>::from(raw_value);
-            value
-        }
-    };
+impl<P, N> core::ops::Deref for NestedMut<'_, P, N> {
+    type Target = N;
 
-    // Field Getter: Disabled
-    (@field @getter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, _, $setter:ident: $($exprs:expr),*) => {};
+    fn deref(&self) -> &N {
+        self.value.as_ref().expect("nested value is only taken on drop")
+    }
+}
 
-    // Field Setter: Bit Range (without conversion)
-    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, _, $setter:ident: $msb:expr, $lsb:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $setter(&mut self, value: $type) -> &mut Self {
-            use $crate::BitRangeMut;
-            self.set_bits($msb, $lsb, value)
-        }
-    };
+impl<P, N> core::ops::DerefMut for NestedMut<'_, P, N> {
+    fn deref_mut(&mut self) -> &mut N {
+        self.value.as_mut().expect("nested value is only taken on drop")
+    }
+}
 
-    // Field Setter: Bit Range (with conversion)
-    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:ty, _, $setter:ident: $msb:expr, $lsb:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $setter(&mut self, value: $from) -> &mut Self
-            where $type: ~const ::core::convert::From<$from>
-        {
-            use $crate::BitRangeMut;
-            let raw_value: $type = <$type>::from(value);
-            self.set_bits($msb, $lsb, raw_value)
+impl<P, N> Drop for NestedMut<'_, P, N> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            (self.write)(self.parent, value);
         }
-    };
+    }
+}
 
-    // Field Setter: Single Bit (without conversion)
-    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, _, _, _, $setter:ident: $bit:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $setter(&mut self, value: $type) -> &mut Self {
-            use $crate::BitMut;
-            self.set_bit($bit, value)
+/// A memory-mapped register holding a bitfield, which is only accessed using volatile reads and
+/// writes of the whole bitfield. As bitfields are declared with `#[repr(transparent)]` unless given a
+/// custom representation, the address of a storage value may be cast into the address of a bitfield
+/// with that storage type:
+/// ```rust
+/// # #![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+/// # #![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+///
+/// use const_bitfield::{bitfield, Register};
+///
+/// bitfield! {
+///     #[derive(Copy, Clone)]
+///     pub struct Control(u32);
+///     bool, enable, set_enable: 0;
+///     u8, divider, set_divider: 15, 8;
+/// }
+///
+/// let mut word: u32 = 0x0000_0300;
+/// let register = unsafe { Register::new(&mut word as *mut u32 as *mut Control) };
+/// register.modify(|control| {
+///     control.set_enable(true);
+/// });
+/// assert_eq!(register.read().divider(), 3);
+/// assert_eq!(word, 0x0000_0301);
+/// ```
+#[derive(Debug)]
+pub struct Register<T> {
+    address: *mut T,
+}
+
+impl<T: Copy> Register<T> {
+    /// Create a new register located at the given address.
+    ///
+    /// # Safety
+    /// The address must be non-null, properly aligned and valid for volatile reads and writes of
+    /// `T` for as long as the register is being used.
+    pub const unsafe fn new(address: *mut T) -> Self {
+        Self { address }
+    }
+
+    /// Return the address of the register.
+    pub const fn as_ptr(&self) -> *mut T {
+        self.address
+    }
+
+    /// Read the current value of the register using a volatile read.
+    pub fn read(&self) -> T {
+        // SAFETY: validity of the address is guaranteed by the caller of `new`
+        unsafe { core::ptr::read_volatile(self.address) }
+    }
+
+    /// Write the given value into the register using a volatile write.
+    pub fn write(&self, value: T) {
+        // SAFETY: validity of the address is guaranteed by the caller of `new`
+        unsafe { core::ptr::write_volatile(self.address, value) }
+    }
+
+    /// Read the register, modify the value using the given closure and write it back. Both accesses
+    /// are volatile, but the sequence as a whole is not atomic.
+    pub fn modify<F: FnOnce(&mut T)>(&self, modify: F) {
+        let mut value = self.read();
+        modify(&mut value);
+        self.write(value);
+    }
+}
+
+/// Derive the ordering of a failed compare-exchange from the ordering used on success, as the
+/// failure ordering can not contain a release.
+#[doc(hidden)]
+pub const fn failure_ordering(ordering: core::sync::atomic::Ordering) -> core::sync::atomic::Ordering {
+    use core::sync::atomic::Ordering;
+    match ordering {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        ordering => ordering,
+    }
+}
+
+/// Helper for printing the raw storage of a bitfield as hexadecimal number within `Debug` output.
+#[doc(hidden)]
+pub struct DebugHex<'a, T>(pub &'a T);
+
+impl<T: BitRange<u8>> core::fmt::Debug for DebugHex<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("0x")?;
+        for index in (0..core::mem::size_of::<T>()).rev() {
+            write!(f, "{:02x}", self.0.bits(index * 8 + 7, index * 8))?;
         }
-    };
+        Ok(())
+    }
+}
 
-    // Field Setter: Single Bit (with conversion)
-    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:ty, $into:ty, _, $setter:ident: $bit:expr) => {
-        $(#[$attributes])*
-        $visibility const fn $setter(&mut self, value: $from) -> &mut Self
-            where $type: ~const ::core::convert::From<$from>
-        {
-            use $crate::BitMut;
-            let raw_value: $type = <$type>::from(value);
-            self.set_bit($bit, raw_value)
+/// Helper for printing the elements of a field array as a list within `Debug` output, given the
+/// number of elements and a function returning the element with the given index.
+#[doc(hidden)]
+pub struct DebugArray<F>(pub usize, pub F);
+
+impl<T: core::fmt::Debug, F: Fn(usize) -> T> core::fmt::Debug for DebugArray<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries((0..self.0).map(&self.1)).finish()
+    }
+}
+
+/// List of field bit ranges used for detecting overlapping fields at compile-time.
+#[doc(hidden)]
+pub enum FieldRanges {
+    /// Bit range of a single field as inclusive `(msb, lsb)` tuple, followed by all other fields.
+    Field {
+        range: (usize, usize),
+        overlap: bool,
+        next: &'static FieldRanges,
+    },
+    /// Bit range of the first element of a field array, with every further element shifted by `step`.
+    Array {
+        range: (usize, usize),
+        count: usize,
+        step: isize,
+        overlap: bool,
+        next: &'static FieldRanges,
+    },
+    /// End of the field list.
+    End,
+}
+
+impl FieldRanges {
+    /// Check if the given field overlaps with any other field, unless both are allowed to overlap.
+    pub const fn conflicts(&self, range: (usize, usize), overlap: bool) -> bool {
+        // count overlapping fields, which includes the field itself unless allowed to overlap
+        let mut count = 0;
+        let mut current = self;
+        loop {
+            let (first, elements, step, other_overlap, next) = match current {
+                FieldRanges::Field { range, overlap, next } => (*range, 1, 0, *overlap, *next),
+                FieldRanges::Array { range, count, step, overlap, next } => (*range, *count, *step, *overlap, *next),
+                FieldRanges::End => break,
+            };
+
+            let mut index = 0;
+            while index < elements {
+                let offset = step * index as isize;
+                let other = (
+                    (first.0 as isize).wrapping_add(offset) as usize,
+                    (first.1 as isize).wrapping_add(offset) as usize,
+                );
+                if range.1 <= other.0 && other.1 <= range.0 && !(overlap && other_overlap) {
+                    count += 1;
+                }
+                index += 1;
+            }
+            current = next;
         }
-    };
 
-    // Field Setter: Disabled
-    (@field @setter $(#[$attributes:meta])* $visibility:vis $type:ty, $from:tt, $into:tt, $getter:ident, _: $($exprs:expr),*) => {};
+        count > if overlap { 0 } else { 1 }
+    }
+}
+
+/// A trait for all types which can be used as backing storage of a bitfield.
+#[cfg_attr(not(feature = "stable"), const_trait)]
+pub trait BitStorage: Copy {
+    /// The storage value with all bits cleared.
+    const ZERO: Self;
+
+    /// Combine two storage values using a bitwise and.
+    fn and(self, rhs: Self) -> Self;
+
+    /// Combine two storage values using a bitwise or.
+    fn or(self, rhs: Self) -> Self;
+
+    /// Combine two storage values using a bitwise xor.
+    fn xor(self, rhs: Self) -> Self;
+
+    /// Invert all bits of the storage value.
+    fn not(self) -> Self;
 }
 
 /// A trait to retrieve a range of bits as type `V`.
-#[const_trait]
+#[cfg_attr(not(feature = "stable"), const_trait)]
 pub trait BitRange<V> {
     /// Get a range of bits between `lsb..=msb` and return as type `V`.
     fn bits(&self, msb: usize, lsb: usize) -> V;
+
+    /// Get a range of bits between `lsb..=msb` and return as type `V`, or an error if out of bounds.
+    ///
+    /// The default implementation checks the range against the size of `Self` and `V` before
+    /// delegating to [`bits`](BitRange::bits).
+    fn try_bits(&self, msb: usize, lsb: usize) -> Result<V, BitfieldError>
+    where
+        Self: Sized,
+    {
+        match check_range(core::mem::size_of::<Self>() * 8, msb, lsb) {
+            Ok(()) if msb - lsb >= core::mem::size_of::<V>() * 8 => Err(BitfieldError::ValueTruncated),
+            Ok(()) => Ok(self.bits(msb, lsb)),
+            Err(error) => Err(error),
+        }
+    }
 }
 
 /// A trait to set a range of bits with the type `V`.
-#[const_trait]
+#[cfg_attr(not(feature = "stable"), const_trait)]
 pub trait BitRangeMut<V>: BitRange<V> {
     /// Set a range of bits between `lsb..=msb` using value `V`.
+    ///
+    /// Signed values are accepted if they fit into the range either in two's complement or as
+    /// unsigned value, e.g. `-8..=15` for a 4-bit range, while [`BitRange::bits`] always returns
+    /// the sign-extended value.
     fn set_bits(&mut self, msb: usize, lsb: usize, value: V) -> &mut Self;
+
+    /// Set a range of bits between `lsb..=msb` using value `V`, or return an error if out of bounds.
+    ///
+    /// The default implementation checks the range against the size of `Self` before delegating to
+    /// [`set_bits`](BitRangeMut::set_bits), as the value itself can not be inspected generically.
+    /// Implementations should override it for returning [`BitfieldError::ValueTruncated`] as well.
+    fn try_set_bits(&mut self, msb: usize, lsb: usize, value: V) -> Result<&mut Self, BitfieldError>
+    where
+        Self: Sized,
+    {
+        match check_range(core::mem::size_of::<Self>() * 8, msb, lsb) {
+            Ok(()) => Ok(self.set_bits(msb, lsb, value)),
+            Err(error) => {
+                // destructors of generic values can not be run within const fns
+                core::mem::forget(value);
+                Err(error)
+            }
+        }
+    }
 }
 
 /// A trait to retrieve a single bit as a boolean.
-#[const_trait]
+#[cfg_attr(not(feature = "stable"), const_trait)]
 pub trait Bit {
     /// Get a single bit and return as boolean. (`true` = set, `false` = clear)
     fn bit(&self, bit: usize) -> bool;
+
+    /// Get a single bit and return as boolean, or an error if out of bounds.
+    ///
+    /// The default implementation checks the bit against the size of `Self` before delegating to
+    /// [`bit`](Bit::bit).
+    fn try_bit(&self, bit: usize) -> Result<bool, BitfieldError>
+    where
+        Self: Sized,
+    {
+        match check_range(core::mem::size_of::<Self>() * 8, bit, bit) {
+            Ok(()) => Ok(self.bit(bit)),
+            Err(error) => Err(error),
+        }
+    }
 }
 
 /// A trait to set a single bit as a boolean.
-#[const_trait]
+#[cfg_attr(not(feature = "stable"), const_trait)]
 pub trait BitMut: Bit {
     /// Set a single bit using a boolean. (`true` = set, `false` = clear)
     fn set_bit(&mut self, bit: usize, value: bool) -> &mut Self;
-}
 
-impl<T: ~ const BitRange<u8>> const Bit for T {
-    fn bit(&self, bit: usize) -> bool {
-        self.bits(bit, bit) != 0
+    /// Set a single bit using a boolean, or return an error if out of bounds.
+    ///
+    /// The default implementation checks the bit against the size of `Self` before delegating to
+    /// [`set_bit`](BitMut::set_bit).
+    fn try_set_bit(&mut self, bit: usize, value: bool) -> Result<&mut Self, BitfieldError>
+    where
+        Self: Sized,
+    {
+        match check_range(core::mem::size_of::<Self>() * 8, bit, bit) {
+            Ok(()) => Ok(self.set_bit(bit, value)),
+            Err(error) => Err(error),
+        }
     }
 }
 
-impl<T: ~ const BitRange<u8> + ~ const BitRangeMut<u8>> const BitMut for T {
-    fn set_bit(&mut self, bit: usize, value: bool) -> &mut Self {
-        self.set_bits(bit, bit, value as u8)
+/// Check the inclusive bit range `lsb..=msb` against the given number of bits.
+const fn check_range(bits: usize, msb: usize, lsb: usize) -> Result<(), BitfieldError> {
+    if lsb >= bits {
+        return Err(BitfieldError::LsbOutOfBounds);
+    }
+    if msb >= bits {
+        return Err(BitfieldError::MsbOutOfBounds);
     }
+    if lsb > msb {
+        return Err(BitfieldError::InvertedRange);
+    }
+    Ok(())
+}
+
+macro_rules! impl_bitstorage {
+    // implement storage for each storage type
+    ($constness:tt $variant:tt, ($storage_type:ty, $($rest:ty),*)) => {
+        impl_bitstorage! {$constness $variant, ($storage_type)}
+        impl_bitstorage! {$constness $variant, ($($rest),*)}
+    };
+
+    // implement storage for uint-based storage type using the native bitwise operators
+    ([$($constness:tt)?] uint, ($storage_type:ty)) => {
+        impl $($constness)? BitStorage for $storage_type {
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn and(self, rhs: Self) -> Self {
+                self & rhs
+            }
+
+            #[inline]
+            fn or(self, rhs: Self) -> Self {
+                self | rhs
+            }
+
+            #[inline]
+            fn xor(self, rhs: Self) -> Self {
+                self ^ rhs
+            }
+
+            #[inline]
+            fn not(self) -> Self {
+                !self
+            }
+        }
+    };
+
+    // implement storage for array-based storage type, combining values element by element
+    ([$($constness:tt)?] array, ($storage_type:ty)) => {
+        impl<const N: usize> $($constness)? BitStorage for [$storage_type; N] {
+            const ZERO: Self = [0; N];
+
+            #[inline]
+            fn and(self, rhs: Self) -> Self {
+                impl_bitstorage!(@elementwise |index| self[index] & rhs[index])
+            }
+
+            #[inline]
+            fn or(self, rhs: Self) -> Self {
+                impl_bitstorage!(@elementwise |index| self[index] | rhs[index])
+            }
+
+            #[inline]
+            fn xor(self, rhs: Self) -> Self {
+                impl_bitstorage!(@elementwise |index| self[index] ^ rhs[index])
+            }
+
+            #[inline]
+            fn not(self) -> Self {
+                impl_bitstorage!(@elementwise |index| !self[index])
+            }
+        }
+    };
+
+    // build an array-based storage value from the given expression for every element
+    (@elementwise |$index:ident| $element:expr) => {{
+        let mut value = Self::ZERO;
+        let mut $index = 0;
+        while $index < N {
+            value[$index] = $element;
+            $index += 1;
+        }
+        value
+    }};
+}
+
+macro_rules! impl_bitstorages {
+    // implement storage for all storage types, as const trait implementations if given
+    ($($constness:tt)?) => {
+        impl_bitstorage! {[$($constness)?] uint, (u8, u16, u32, u64, u128)}
+        impl_bitstorage! {[$($constness)?] array, (u8, u16, u32, u64)}
+    };
 }
 
+#[cfg(not(feature = "stable"))]
+impl_bitstorages! {const}
+
+#[cfg(feature = "stable")]
+impl_bitstorages! {}
+
 macro_rules! impl_bitrange {
     // implement given range types for each storage type
-    ($variant:tt, ($storage_type:ty, $($rest:ty),*), ($($range_type:ty),*)) => {
-        impl_bitrange! {$variant, ($storage_type), ($($range_type),*)}
-        impl_bitrange! {$variant, ($($rest),*), ($($range_type),*)}
+    ($constness:tt $variant:tt, ($storage_type:ty, $($rest:ty),*), ($($range_type:ty),*)) => {
+        impl_bitrange! {$constness $variant, ($storage_type), ($($range_type),*)}
+        impl_bitrange! {$constness $variant, ($($rest),*), ($($range_type),*)}
     };
 
     // implement given range types for storage type
-    ($variant:tt, ($storage_type:ty), ($($range_type:ty),*)) => {
-        $(impl_bitrange! {$variant, $storage_type, $range_type})*
+    ($constness:tt $variant:tt, ($storage_type:ty), ($($range_type:ty),*)) => {
+        $(impl_bitrange! {$constness $variant, $storage_type, $range_type})*
     };
 
     // implement bit range for uint-based storage type
-    (uint, $storage_type:ty, $range_type:ty) => {
-        impl const BitRange<$range_type> for $storage_type {
+    ([$($constness:tt)?] uint, $storage_type:ty, $range_type:ty) => {
+        impl $($constness)? BitRange<$range_type> for $storage_type {
             #[inline]
             fn bits(&self, msb: usize, lsb: usize) -> $range_type {
+                match self.try_bits(msb, lsb) {
+                    Ok(value) => value,
+                    Err(error) => panic!("{}", error.message()),
+                }
+            }
+
+            #[inline]
+            fn try_bits(&self, msb: usize, lsb: usize) -> Result<$range_type, BitfieldError> {
                 // treat both range bounds as inclusive
                 let msb = msb + 1;
 
@@ -274,48 +3177,212 @@ macro_rules! impl_bitrange {
                 let range_bits = ::core::mem::size_of::<$range_type>() * 8;
 
                 // check input range boundaries
-                assert!(lsb < storage_bits, "lsb is out of bounds for bit range");
-                assert!(msb <= storage_bits, "msb is out of bounds for bit range");
-                assert!(lsb <= msb, "lsb must not be greater than msb for bit range");
-                assert!((msb - lsb) <= range_bits, "value truncated in bit range operation");
+                if lsb >= storage_bits {
+                    return Err(BitfieldError::LsbOutOfBounds);
+                }
+                if msb > storage_bits {
+                    return Err(BitfieldError::MsbOutOfBounds);
+                }
+                if lsb >= msb {
+                    return Err(BitfieldError::InvertedRange);
+                }
+                if (msb - lsb) > range_bits {
+                    return Err(BitfieldError::ValueTruncated);
+                }
 
                 // shift away unnecessary high and low bits
-                (*self << (storage_bits - msb) >> (storage_bits - msb) >> lsb) as $range_type
+                let value = (*self << (storage_bits - msb) >> (storage_bits - msb) >> lsb) as $range_type;
+
+                // sign-extend value for signed range types, no-op for unsigned range types
+                let unused_bits = range_bits - (msb - lsb);
+                Ok(value << unused_bits >> unused_bits)
             }
         }
 
-        impl const BitRangeMut<$range_type> for $storage_type {
+        impl $($constness)? BitRangeMut<$range_type> for $storage_type {
             #[inline]
             fn set_bits(&mut self, msb: usize, lsb: usize, value: $range_type) -> &mut Self {
+                match self.try_set_bits(msb, lsb, value) {
+                    Ok(this) => this,
+                    Err(error) => panic!("{}", error.message()),
+                }
+            }
+
+            #[inline]
+            fn try_set_bits(&mut self, msb: usize, lsb: usize, value: $range_type) -> Result<&mut Self, BitfieldError> {
                 // treat both range bounds as inclusive
                 let msb = msb + 1;
 
                 // determine number of bits
                 let storage_bits = ::core::mem::size_of::<$storage_type>() * 8;
+                let range_bits = ::core::mem::size_of::<$range_type>() * 8;
 
                 // check range boundaries
-                assert!(lsb < storage_bits, "lsb is out of bounds for bit range");
-                assert!(msb <= storage_bits, "msb is out of bounds for bit range");
-                assert!(lsb < msb, "lsb must not be greater than msb for bit range");
+                if lsb >= storage_bits {
+                    return Err(BitfieldError::LsbOutOfBounds);
+                }
+                if msb > storage_bits {
+                    return Err(BitfieldError::MsbOutOfBounds);
+                }
+                if lsb >= msb {
+                    return Err(BitfieldError::InvertedRange);
+                }
 
-                // ensure value does not get truncated
-                let new_value = value as $storage_type;
-                let dropped_bits = storage_bits - (msb - lsb);
-                assert!(
-                    (new_value << dropped_bits >> dropped_bits) as $range_type == value,
-                    "value truncated in bit range operation"
-                );
+                // ensure value does not get truncated, accepting both the zero-extended and the
+                // sign-extended (two's complement) representation for signed range types, so that
+                // unsuffixed literals inferred as i32 keep fitting, e.g. -8..=15 for a 4-bit field
+                let field_bits = msb - lsb;
+                if field_bits < range_bits {
+                    let unused_bits = range_bits - field_bits;
+                    if value >> field_bits != 0 && value << unused_bits >> unused_bits != value {
+                        return Err(BitfieldError::ValueTruncated);
+                    }
+                }
+
+                // drop sign-extended high bits beyond the field width
+                let dropped_bits = storage_bits - field_bits;
+                let new_value = (value as $storage_type) << dropped_bits >> dropped_bits;
 
                 // calculate mask for clearing bits
                 let mask = !((!0 as $storage_type) << (storage_bits - msb) >> (storage_bits - msb) >> lsb << lsb);
 
                 // clear bits and OR with new value
                 *self = (*self & mask) | (new_value << lsb);
-                self
+                Ok(self)
+            }
+        }
+    };
+
+    // implement bit range for array-based storage type, with element 0 holding the lowest bits
+    ([$($constness:tt)?] array, $storage_type:ty, $range_type:ty) => {
+        impl<const N: usize> $($constness)? BitRange<$range_type> for [$storage_type; N] {
+            #[inline]
+            fn bits(&self, msb: usize, lsb: usize) -> $range_type {
+                match self.try_bits(msb, lsb) {
+                    Ok(value) => value,
+                    Err(error) => panic!("{}", error.message()),
+                }
+            }
+
+            #[inline]
+            fn try_bits(&self, msb: usize, lsb: usize) -> Result<$range_type, BitfieldError> {
+                // treat both range bounds as inclusive
+                let msb = msb + 1;
+
+                // determine number of bits
+                let element_bits = ::core::mem::size_of::<$storage_type>() * 8;
+                let storage_bits = element_bits * N;
+                let range_bits = ::core::mem::size_of::<$range_type>() * 8;
+
+                // check input range boundaries
+                if lsb >= storage_bits {
+                    return Err(BitfieldError::LsbOutOfBounds);
+                }
+                if msb > storage_bits {
+                    return Err(BitfieldError::MsbOutOfBounds);
+                }
+                if lsb >= msb {
+                    return Err(BitfieldError::InvertedRange);
+                }
+                if (msb - lsb) > range_bits {
+                    return Err(BitfieldError::ValueTruncated);
+                }
+
+                // collect bits from every element touched by the range
+                let mut value: $range_type = 0;
+                let mut bit = lsb;
+                while bit < msb {
+                    let offset = bit % element_bits;
+                    let count = if msb - bit < element_bits - offset { msb - bit } else { element_bits - offset };
+                    let chunk: $storage_type = self[bit / element_bits].bits(offset + count - 1, offset);
+                    value |= (chunk as $range_type) << (bit - lsb);
+                    bit += count;
+                }
+
+                // sign-extend value for signed range types, no-op for unsigned range types
+                let unused_bits = range_bits - (msb - lsb);
+                Ok(value << unused_bits >> unused_bits)
+            }
+        }
+
+        impl<const N: usize> $($constness)? BitRangeMut<$range_type> for [$storage_type; N] {
+            #[inline]
+            fn set_bits(&mut self, msb: usize, lsb: usize, value: $range_type) -> &mut Self {
+                match self.try_set_bits(msb, lsb, value) {
+                    Ok(this) => this,
+                    Err(error) => panic!("{}", error.message()),
+                }
+            }
+
+            #[inline]
+            fn try_set_bits(&mut self, msb: usize, lsb: usize, value: $range_type) -> Result<&mut Self, BitfieldError> {
+                // treat both range bounds as inclusive
+                let msb = msb + 1;
+
+                // determine number of bits
+                let element_bits = ::core::mem::size_of::<$storage_type>() * 8;
+                let storage_bits = element_bits * N;
+                let range_bits = ::core::mem::size_of::<$range_type>() * 8;
+
+                // check range boundaries
+                if lsb >= storage_bits {
+                    return Err(BitfieldError::LsbOutOfBounds);
+                }
+                if msb > storage_bits {
+                    return Err(BitfieldError::MsbOutOfBounds);
+                }
+                if lsb >= msb {
+                    return Err(BitfieldError::InvertedRange);
+                }
+
+                // ensure value does not get truncated, accepting both the zero-extended and the
+                // sign-extended (two's complement) representation for signed range types, so that
+                // unsuffixed literals inferred as i32 keep fitting, e.g. -8..=15 for a 4-bit field
+                let field_bits = msb - lsb;
+                if field_bits < range_bits {
+                    let unused_bits = range_bits - field_bits;
+                    if value >> field_bits != 0 && value << unused_bits >> unused_bits != value {
+                        return Err(BitfieldError::ValueTruncated);
+                    }
+                }
+
+                // bits beyond the range type are filled with the sign for signed range types
+                let sign_fill: $storage_type = if <$range_type>::MIN != 0 && value >> (range_bits - 1) != 0 {
+                    !0
+                } else {
+                    0
+                };
+
+                // distribute bits onto every element touched by the range
+                let mut bit = lsb;
+                while bit < msb {
+                    let offset = bit % element_bits;
+                    let count = if msb - bit < element_bits - offset { msb - bit } else { element_bits - offset };
+                    let shift = bit - lsb;
+                    let chunk = if shift < range_bits { (value >> shift) as $storage_type } else { sign_fill };
+                    let dropped_bits = element_bits - count;
+                    self[bit / element_bits].set_bits(offset + count - 1, offset, chunk << dropped_bits >> dropped_bits);
+                    bit += count;
+                }
+
+                Ok(self)
             }
         }
     };
 }
 
-impl_bitrange! {uint, (u8, u16, u32, u64, u128), (u8, u16, u32, u64, u128)}
-impl_bitrange! {uint, (u8, u16, u32, u64, u128), (i8, i16, i32, i64, i128)}
+macro_rules! impl_bitranges {
+    // implement bit ranges for all storage and range types, as const trait implementations if given
+    ($($constness:tt)?) => {
+        impl_bitrange! {[$($constness)?] uint, (u8, u16, u32, u64, u128), (u8, u16, u32, u64, u128)}
+        impl_bitrange! {[$($constness)?] uint, (u8, u16, u32, u64, u128), (i8, i16, i32, i64, i128)}
+        impl_bitrange! {[$($constness)?] array, (u8, u16, u32, u64), (u8, u16, u32, u64, u128)}
+        impl_bitrange! {[$($constness)?] array, (u8, u16, u32, u64), (i8, i16, i32, i64, i128)}
+    };
+}
+
+#[cfg(not(feature = "stable"))]
+impl_bitranges! {const}
+
+#[cfg(feature = "stable")]
+impl_bitranges! {}
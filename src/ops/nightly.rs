@@ -0,0 +1,140 @@
+//! Bit operations used by the `bitfield!` macro, implemented using the const bit traits.
+
+use crate::{Bit, BitMut, BitRange, BitRangeMut, BitfieldError};
+
+impl<T: ~const BitRange<u8>> const Bit for T {
+    fn bit(&self, bit: usize) -> bool {
+        self.bits(bit, bit) != 0
+    }
+
+    fn try_bit(&self, bit: usize) -> Result<bool, BitfieldError> {
+        match self.try_bits(bit, bit) {
+            Ok(value) => Ok(value != 0),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<T: ~const BitRange<u8> + ~const BitRangeMut<u8>> const BitMut for T {
+    fn set_bit(&mut self, bit: usize, value: bool) -> &mut Self {
+        self.set_bits(bit, bit, value as u8)
+    }
+
+    fn try_set_bit(&mut self, bit: usize, value: bool) -> Result<&mut Self, BitfieldError> {
+        self.try_set_bits(bit, bit, value as u8)
+    }
+}
+
+/// Get a range of bits between `lsb..=msb` of the storage and return as type `V`.
+#[inline]
+pub const fn bits<S: ~const BitRange<V>, V>(storage: &S, msb: usize, lsb: usize) -> V {
+    storage.bits(msb, lsb)
+}
+
+/// Set a range of bits between `lsb..=msb` of the storage using value `V`.
+#[inline]
+pub const fn set_bits<S: ~const BitRangeMut<V>, V>(storage: &mut S, msb: usize, lsb: usize, value: V) {
+    storage.set_bits(msb, lsb, value);
+}
+
+/// Set a range of bits between `lsb..=msb` of the storage using value `V`, or return an error.
+#[inline]
+pub const fn try_set_bits<S: ~const BitRangeMut<V>, V>(
+    storage: &mut S,
+    msb: usize,
+    lsb: usize,
+    value: V,
+) -> Result<(), BitfieldError> {
+    match storage.try_set_bits(msb, lsb, value) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Get a single bit of the storage and return as boolean.
+#[inline]
+pub const fn bit<S: ~const Bit>(storage: &S, bit: usize) -> bool {
+    storage.bit(bit)
+}
+
+/// Set a single bit of the storage using a boolean.
+#[inline]
+pub const fn set_bit<S: ~const BitMut>(storage: &mut S, bit: usize, value: bool) {
+    storage.set_bit(bit, value);
+}
+
+/// Set a single bit of the storage using a boolean, or return an error.
+#[inline]
+pub const fn try_set_bit<S: ~const BitMut>(storage: &mut S, bit: usize, value: bool) -> Result<(), BitfieldError> {
+    match storage.try_set_bit(bit, value) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Generate a const fn with const trait bounds, used for fields relying on `From` or `TryFrom`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_fn {
+    (
+        $(#[$attributes:meta])*
+        $visibility:vis fn $name:ident($($arguments:tt)*) -> $output:ty
+            where [$bound:ty: $trait:path]
+        $body:block
+    ) => {
+        $(#[$attributes])*
+        $visibility const fn $name($($arguments)*) -> $output
+            where $bound: ~const $trait
+        $body
+    };
+}
+
+/// Implement the const traits `BitRange<T>` and `BitRangeMut<T>` for struct(pub T).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_impl_range {
+    (struct $name:ident($type:ty)) => {
+        impl<T> const $crate::BitRange<T> for $name
+        where
+            $type: ~const $crate::BitRange<T>
+        {
+            #[inline]
+            fn bits(&self, msb: usize, lsb: usize) -> T {
+                self.0.bits(msb, lsb)
+            }
+
+            #[inline]
+            fn try_bits(&self, msb: usize, lsb: usize) -> ::core::result::Result<T, $crate::BitfieldError> {
+                self.0.try_bits(msb, lsb)
+            }
+        }
+
+        impl<T> const $crate::BitRangeMut<T> for $name
+        where
+            $type: ~const $crate::BitRange<T> + ~const $crate::BitRangeMut<T>
+        {
+            #[inline]
+            fn set_bits(&mut self, msb: usize, lsb: usize, value: T) -> &mut Self {
+                self.0.set_bits(msb, lsb, value);
+                self
+            }
+
+            #[inline]
+            fn try_set_bits(&mut self, msb: usize, lsb: usize, value: T) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                match self.0.try_set_bits(msb, lsb, value) {
+                    Ok(_) => Ok(self),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+}
+
+/// Generate a const trait implementation, used for operator traits of the bitfield.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_impl_const {
+    (impl $trait:path, for $name:ident $body:tt) => {
+        impl const $trait for $name $body
+    };
+}
@@ -0,0 +1,347 @@
+//! Bit operations used by the `bitfield!` macro, implemented as plain const fns for Rust stable.
+//!
+//! As trait methods can not be called within const fns on Rust stable, these functions access the
+//! elements of the storage directly, using shift and mask arithmetic instead of the bit traits.
+
+use crate::{Bit, BitMut, BitRange, BitRangeMut, BitfieldError};
+use core::mem::size_of;
+
+impl<T: BitRange<u8>> Bit for T {
+    fn bit(&self, bit: usize) -> bool {
+        self.bits(bit, bit) != 0
+    }
+
+    fn try_bit(&self, bit: usize) -> Result<bool, BitfieldError> {
+        match self.try_bits(bit, bit) {
+            Ok(value) => Ok(value != 0),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<T: BitRange<u8> + BitRangeMut<u8>> BitMut for T {
+    fn set_bit(&mut self, bit: usize, value: bool) -> &mut Self {
+        self.set_bits(bit, bit, value as u8)
+    }
+
+    fn try_set_bit(&mut self, bit: usize, value: bool) -> Result<&mut Self, BitfieldError> {
+        self.try_set_bits(bit, bit, value as u8)
+    }
+}
+
+/// A storage type consisting of unsigned integer elements, which can be accessed within const fns.
+///
+/// # Safety
+/// Implementors must be an unsigned integer or an array of unsigned integers, each consisting of
+/// `ELEMENT_BITS` bits, with element 0 holding the lowest bits.
+pub unsafe trait Storage: Copy {
+    /// Number of bits of a single storage element.
+    const ELEMENT_BITS: usize;
+}
+
+/// A value type which can be read from and written into a bit range within const fns.
+///
+/// # Safety
+/// Implementors must be primitive integers, for which every bit pattern is valid.
+pub unsafe trait Value: Copy {
+    /// Whether the value type is signed and requires sign extension.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_storage {
+    ($($storage_type:ty),*) => {
+        $(
+            // SAFETY: a single unsigned integer is its only element
+            unsafe impl Storage for $storage_type {
+                const ELEMENT_BITS: usize = <$storage_type>::BITS as usize;
+            }
+
+            // SAFETY: arrays are laid out without padding, starting with the element at index 0
+            unsafe impl<const N: usize> Storage for [$storage_type; N] {
+                const ELEMENT_BITS: usize = <$storage_type>::BITS as usize;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_value {
+    ($signed:literal, ($($value_type:ty),*)) => {
+        // SAFETY: primitive integers accept every bit pattern
+        $(unsafe impl Value for $value_type {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_storage! {u8, u16, u32, u64}
+// SAFETY: a single unsigned integer is its only element
+unsafe impl Storage for u128 {
+    const ELEMENT_BITS: usize = u128::BITS as usize;
+}
+
+impl_value! {false, (u8, u16, u32, u64, u128)}
+impl_value! {true, (i8, i16, i32, i64, i128)}
+
+/// Get the storage element with the given index, which must lie within the storage.
+const fn element<S: Storage>(storage: &S, index: usize) -> u128 {
+    assert!(index < size_of::<S>() * 8 / S::ELEMENT_BITS);
+    let elements = storage as *const S;
+
+    // SAFETY: `Storage` guarantees that `S` consists of unsigned integers of `ELEMENT_BITS` bits,
+    // so the pointer is aligned for the element type, and the index has been checked against the
+    // number of elements
+    unsafe {
+        match S::ELEMENT_BITS {
+            8 => *(elements as *const u8).add(index) as u128,
+            16 => *(elements as *const u16).add(index) as u128,
+            32 => *(elements as *const u32).add(index) as u128,
+            64 => *(elements as *const u64).add(index) as u128,
+            _ => *(elements as *const u128).add(index),
+        }
+    }
+}
+
+/// Replace the storage element with the given index, which must lie within the storage.
+const fn set_element<S: Storage>(storage: &mut S, index: usize, value: u128) {
+    assert!(index < size_of::<S>() * 8 / S::ELEMENT_BITS);
+    let elements = storage as *mut S;
+
+    // SAFETY: `Storage` guarantees that `S` consists of unsigned integers of `ELEMENT_BITS` bits,
+    // so the pointer is aligned for the element type, and the index has been checked against the
+    // number of elements, while the value is truncated to the element type
+    unsafe {
+        match S::ELEMENT_BITS {
+            8 => *(elements as *mut u8).add(index) = value as u8,
+            16 => *(elements as *mut u16).add(index) = value as u16,
+            32 => *(elements as *mut u32).add(index) = value as u32,
+            64 => *(elements as *mut u64).add(index) = value as u64,
+            _ => *(elements as *mut u128).add(index) = value,
+        }
+    }
+}
+
+/// Get a mask of the given number of lowest bits, which must be between 1 and 128.
+const fn mask(bits: usize) -> u128 {
+    u128::MAX >> (128 - bits)
+}
+
+/// Check the boundaries of the range `lsb..msb`, with the msb already being exclusive.
+const fn check_range<S: Storage>(msb: usize, lsb: usize) -> Result<(), BitfieldError> {
+    let storage_bits = size_of::<S>() * 8;
+    if lsb >= storage_bits {
+        return Err(BitfieldError::LsbOutOfBounds);
+    }
+    if msb > storage_bits {
+        return Err(BitfieldError::MsbOutOfBounds);
+    }
+    if lsb >= msb {
+        return Err(BitfieldError::InvertedRange);
+    }
+    Ok(())
+}
+
+/// Convert the lowest bits of a raw value into the value type.
+const fn from_raw<V: Value>(raw: u128) -> V {
+    let bytes = raw.to_ne_bytes();
+    let offset = if cfg!(target_endian = "big") { 16 - size_of::<V>() } else { 0 };
+
+    // SAFETY: `V` is a primitive integer of at most 16 bytes, for which every bit pattern is valid
+    unsafe { core::ptr::read_unaligned((&bytes as *const [u8; 16] as *const u8).add(offset) as *const V) }
+}
+
+/// Convert a value into a raw value, with sign extension for signed value types.
+const fn into_raw<V: Value>(value: V) -> u128 {
+    let mut bytes = [0; 16];
+    let offset = if cfg!(target_endian = "big") { 16 - size_of::<V>() } else { 0 };
+
+    // SAFETY: `V` is a primitive integer of at most 16 bytes, which fits into the buffer
+    unsafe { core::ptr::write_unaligned((&mut bytes as *mut [u8; 16] as *mut u8).add(offset) as *mut V, value) };
+
+    let raw = u128::from_ne_bytes(bytes);
+    let range_bits = size_of::<V>() * 8;
+    if V::SIGNED && range_bits < 128 && raw >> (range_bits - 1) != 0 {
+        raw | u128::MAX << range_bits
+    } else {
+        raw
+    }
+}
+
+/// Get a range of bits between `lsb..=msb` of the storage and return as type `V`, or an error.
+pub const fn try_bits<S: Storage, V: Value>(storage: &S, msb: usize, lsb: usize) -> Result<V, BitfieldError> {
+    // treat both range bounds as inclusive
+    let msb = msb + 1;
+    if let Err(error) = check_range::<S>(msb, lsb) {
+        return Err(error);
+    }
+    if (msb - lsb) > size_of::<V>() * 8 {
+        return Err(BitfieldError::ValueTruncated);
+    }
+
+    // collect bits from every storage element touched by the range
+    let mut value: u128 = 0;
+    let mut bit = lsb;
+    while bit < msb {
+        let offset = bit % S::ELEMENT_BITS;
+        let count = if msb - bit < S::ELEMENT_BITS - offset { msb - bit } else { S::ELEMENT_BITS - offset };
+        let chunk = (element(storage, bit / S::ELEMENT_BITS) >> offset) & mask(count);
+        value |= chunk << (bit - lsb);
+        bit += count;
+    }
+
+    // sign-extend value for signed range types
+    let field_bits = msb - lsb;
+    if V::SIGNED && field_bits < 128 && value >> (field_bits - 1) != 0 {
+        value |= u128::MAX << field_bits;
+    }
+    Ok(from_raw(value))
+}
+
+/// Set a range of bits between `lsb..=msb` of the storage using value `V`, or return an error.
+pub const fn try_set_bits<S: Storage, V: Value>(
+    storage: &mut S,
+    msb: usize,
+    lsb: usize,
+    value: V,
+) -> Result<(), BitfieldError> {
+    // treat both range bounds as inclusive
+    let msb = msb + 1;
+    if let Err(error) = check_range::<S>(msb, lsb) {
+        return Err(error);
+    }
+
+    // ensure value does not get truncated, accepting both the zero-extended and the
+    // sign-extended (two's complement) representation for signed range types, so that
+    // unsuffixed literals inferred as i32 keep fitting, e.g. -8..=15 for a 4-bit field
+    let raw = into_raw(value);
+    let field_bits = msb - lsb;
+    if field_bits < size_of::<V>() * 8 {
+        let unused_bits = 128 - field_bits;
+        let sign_extended = ((raw as i128) << unused_bits >> unused_bits) as u128 == raw;
+        if raw >> field_bits != 0 && !(V::SIGNED && sign_extended) {
+            return Err(BitfieldError::ValueTruncated);
+        }
+    }
+
+    // bits beyond the raw value are filled with its sign
+    let sign_fill: u128 = if raw >> 127 != 0 { u128::MAX } else { 0 };
+
+    // distribute bits onto every storage element touched by the range
+    let mut bit = lsb;
+    while bit < msb {
+        let offset = bit % S::ELEMENT_BITS;
+        let count = if msb - bit < S::ELEMENT_BITS - offset { msb - bit } else { S::ELEMENT_BITS - offset };
+        let shift = bit - lsb;
+        let chunk = if shift < 128 { raw >> shift } else { sign_fill };
+        let mask = mask(count) << offset;
+
+        let index = bit / S::ELEMENT_BITS;
+        set_element(storage, index, (element(storage, index) & !mask) | ((chunk << offset) & mask));
+        bit += count;
+    }
+
+    Ok(())
+}
+
+/// Get a range of bits between `lsb..=msb` of the storage and return as type `V`.
+#[inline]
+pub const fn bits<S: Storage, V: Value>(storage: &S, msb: usize, lsb: usize) -> V {
+    match try_bits(storage, msb, lsb) {
+        Ok(value) => value,
+        Err(error) => panic!("{}", error.message()),
+    }
+}
+
+/// Set a range of bits between `lsb..=msb` of the storage using value `V`.
+#[inline]
+pub const fn set_bits<S: Storage, V: Value>(storage: &mut S, msb: usize, lsb: usize, value: V) {
+    if let Err(error) = try_set_bits(storage, msb, lsb, value) {
+        panic!("{}", error.message());
+    }
+}
+
+/// Get a single bit of the storage and return as boolean.
+#[inline]
+pub const fn bit<S: Storage>(storage: &S, bit: usize) -> bool {
+    bits::<S, u8>(storage, bit, bit) != 0
+}
+
+/// Set a single bit of the storage using a boolean.
+#[inline]
+pub const fn set_bit<S: Storage>(storage: &mut S, bit: usize, value: bool) {
+    set_bits(storage, bit, bit, value as u8);
+}
+
+/// Set a single bit of the storage using a boolean, or return an error.
+#[inline]
+pub const fn try_set_bit<S: Storage>(storage: &mut S, bit: usize, value: bool) -> Result<(), BitfieldError> {
+    try_set_bits(storage, bit, bit, value as u8)
+}
+
+/// Generate a regular fn, used for fields relying on `From` or `TryFrom` which can not be called
+/// within const fns on Rust stable.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_fn {
+    (
+        $(#[$attributes:meta])*
+        $visibility:vis fn $name:ident($($arguments:tt)*) -> $output:ty
+            where [$bound:ty: $trait:path]
+        $body:block
+    ) => {
+        $(#[$attributes])*
+        $visibility fn $name($($arguments)*) -> $output
+            where $bound: $trait
+        $body
+    };
+}
+
+/// Implement the traits `BitRange<T>` and `BitRangeMut<T>` for struct(pub T).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_impl_range {
+    (struct $name:ident($type:ty)) => {
+        impl<T> $crate::BitRange<T> for $name
+        where
+            $type: $crate::BitRange<T>
+        {
+            #[inline]
+            fn bits(&self, msb: usize, lsb: usize) -> T {
+                self.0.bits(msb, lsb)
+            }
+
+            #[inline]
+            fn try_bits(&self, msb: usize, lsb: usize) -> ::core::result::Result<T, $crate::BitfieldError> {
+                self.0.try_bits(msb, lsb)
+            }
+        }
+
+        impl<T> $crate::BitRangeMut<T> for $name
+        where
+            $type: $crate::BitRange<T> + $crate::BitRangeMut<T>
+        {
+            #[inline]
+            fn set_bits(&mut self, msb: usize, lsb: usize, value: T) -> &mut Self {
+                self.0.set_bits(msb, lsb, value);
+                self
+            }
+
+            #[inline]
+            fn try_set_bits(&mut self, msb: usize, lsb: usize, value: T) -> ::core::result::Result<&mut Self, $crate::BitfieldError> {
+                match self.0.try_set_bits(msb, lsb, value) {
+                    Ok(_) => Ok(self),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    };
+}
+
+/// Generate a regular trait implementation, as const trait implementations are not available on
+/// Rust stable.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_impl_const {
+    (impl $trait:path, for $name:ident $body:tt) => {
+        impl $trait for $name $body
+    };
+}
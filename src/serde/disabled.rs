@@ -0,0 +1,14 @@
+//! Placeholder for the serde directives of the `bitfield!` macro when the `serde` feature is disabled.
+
+/// Reject serde directives, as the `serde` feature is required for implementing them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_serde {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`impl ",
+            ::core::stringify!($directive),
+            ";` requires the `serde` feature of const-bitfield",
+        ));
+    };
+}
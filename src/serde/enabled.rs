@@ -0,0 +1,116 @@
+//! Support for the `impl Serde;` and `impl SerdeRaw;` directives of the `bitfield!` macro.
+
+use crate::BitfieldError;
+use core::fmt;
+use core::marker::PhantomData;
+use ::serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use ::serde::ser::SerializeSeq;
+
+pub use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Seed for deserializing the key of a field, rejecting all names not known to the bitfield.
+pub struct FieldKey(pub fn(&str) -> Option<&'static str>);
+
+impl<'de> DeserializeSeed<'de> for FieldKey {
+    type Value = &'static str;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldKey {
+    type Value = &'static str;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the name of a field")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match (self.0)(value) {
+            Some(name) => Ok(name),
+            None => Err(E::custom(format_args!("unknown field `{}`", value))),
+        }
+    }
+}
+
+/// Serializer for the elements of a field array as a sequence, given the number of elements and a
+/// function returning the element with the given index or an error message if it is invalid.
+pub struct ArrayElements<F>(pub usize, pub F);
+
+impl<T: Serialize, F: Fn(usize) -> Result<T, &'static str>> Serialize for ArrayElements<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0))?;
+        for index in 0..self.0 {
+            match (self.1)(index) {
+                Ok(element) => seq.serialize_element(&element)?,
+                Err(message) => return Err(ser::Error::custom(message)),
+            }
+        }
+        seq.end()
+    }
+}
+
+/// Seed for deserializing the elements of a field array from a sequence, applying every element
+/// using the given function and requiring exactly as many elements as the field array holds.
+pub struct ArraySeed<V, F> {
+    name: &'static str,
+    count: usize,
+    set: F,
+    element: PhantomData<fn(V)>,
+}
+
+impl<V, F: FnMut(usize, V) -> Result<(), BitfieldError>> ArraySeed<V, F> {
+    /// Create a new seed for the field array with the given name and number of elements.
+    pub fn new(name: &'static str, count: usize, set: F) -> Self {
+        Self {
+            name,
+            count,
+            set,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<'de, V: Deserialize<'de>, F: FnMut(usize, V) -> Result<(), BitfieldError>> DeserializeSeed<'de> for ArraySeed<V, F> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, V: Deserialize<'de>, F: FnMut(usize, V) -> Result<(), BitfieldError>> Visitor<'de> for ArraySeed<V, F> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} elements", self.count)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut index = 0;
+        while let Some(element) = seq.next_element::<V>()? {
+            if index == self.count {
+                return Err(de::Error::invalid_length(index + 1, &self));
+            }
+            if let Err(error) = (self.set)(index, element) {
+                return Err(de::Error::custom(format_args!("invalid value for field `{}`: {}", self.name, error)));
+            }
+            index += 1;
+        }
+
+        if index != self.count {
+            return Err(de::Error::invalid_length(index, &self));
+        }
+        Ok(())
+    }
+}
+
+/// Pass through the implementation generated by a serde directive.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_serde {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
@@ -0,0 +1,14 @@
+//! Placeholder for the ufmt directive of the `bitfield!` macro when the `ufmt` feature is disabled.
+
+/// Reject the ufmt directive, as the `ufmt` feature is required for implementing it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_ufmt {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "`impl ",
+            ::core::stringify!($directive),
+            ";` requires the `ufmt` feature of const-bitfield",
+        ));
+    };
+}
@@ -0,0 +1,34 @@
+//! Support for the `impl uDebug;` directive of the `bitfield!` macro.
+
+use crate::{BitRange, DebugArray, DebugHex};
+
+pub use ::ufmt::{uDebug, uWrite, Formatter};
+
+impl<T: BitRange<u8>> uDebug for DebugHex<'_, T> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        f.write_str("0x")?;
+        for index in (0..core::mem::size_of::<T>()).rev() {
+            let byte: u8 = self.0.bits(index * 8 + 7, index * 8);
+            f.write_char(DIGITS[(byte >> 4) as usize] as char)?;
+            f.write_char(DIGITS[(byte & 0xF) as usize] as char)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: uDebug, F: Fn(usize) -> T> uDebug for DebugArray<F> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_list()?.entries((0..self.0).map(&self.1))?.finish()
+    }
+}
+
+/// Pass through the implementation generated by the `impl uDebug;` directive.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_ufmt {
+    (impl $directive:ident; $($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
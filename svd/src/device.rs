@@ -0,0 +1,405 @@
+//! Model of the parts of an SVD device description relevant for generating bitfields.
+
+use crate::Error;
+use roxmltree::{Document, Node};
+
+pub(crate) struct Device {
+    pub name: String,
+    pub peripherals: Vec<Peripheral>,
+}
+
+pub(crate) struct Peripheral {
+    pub name: String,
+    pub description: Option<String>,
+    pub derived_from: Option<String>,
+    pub items: Vec<Item>,
+}
+
+pub(crate) enum Item {
+    Register(Register),
+    Cluster(Cluster),
+}
+
+pub(crate) struct Cluster {
+    pub name: String,
+    pub description: Option<String>,
+    pub items: Vec<Item>,
+}
+
+pub(crate) struct Register {
+    pub name: String,
+    pub description: Option<String>,
+    pub derived_from: Option<String>,
+    pub size: u32,
+    pub access: Access,
+    pub reset_value: Option<u64>,
+    pub fields: Vec<Field>,
+}
+
+pub(crate) struct Field {
+    pub name: String,
+    pub description: Option<String>,
+    pub msb: u32,
+    pub lsb: u32,
+    pub access: Access,
+    pub enumeration: Option<Enumeration>,
+}
+
+/// Enumerated values of a field, shared by all fields expanded from a field array.
+#[derive(Clone)]
+pub(crate) struct Enumeration {
+    pub field: String,
+    pub values: Vec<Value>,
+}
+
+#[derive(Clone)]
+pub(crate) struct Value {
+    pub name: String,
+    pub description: Option<String>,
+    pub value: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Access {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl Access {
+    pub fn is_readable(self) -> bool {
+        self != Access::Write
+    }
+
+    pub fn is_writable(self) -> bool {
+        self != Access::Read
+    }
+}
+
+/// Register properties inherited from the device over peripherals and clusters down to registers.
+#[derive(Copy, Clone, Default)]
+struct Properties {
+    size: Option<u64>,
+    access: Option<Access>,
+    reset_value: Option<u64>,
+    reset_mask: Option<u64>,
+}
+
+impl Properties {
+    fn inherit(self, node: Node, path: &str) -> Result<Self, Error> {
+        Ok(Properties {
+            size: optional(node, "size", path)?.or(self.size),
+            access: match child_text(node, "access") {
+                Some(access) => Some(access_from(access, path)?),
+                None => self.access,
+            },
+            reset_value: optional(node, "resetValue", path)?.or(self.reset_value),
+            reset_mask: optional(node, "resetMask", path)?.or(self.reset_mask),
+        })
+    }
+}
+
+pub(crate) fn parse(svd: &str) -> Result<Device, Error> {
+    let document = Document::parse(svd)?;
+    let root = document.root_element();
+    if !root.has_tag_name("device") {
+        return Err(Error::svd(root.tag_name().name(), "root element must be `device`"));
+    }
+
+    let name = required(root, "name", "device")?.to_string();
+    let properties = Properties::default().inherit(root, &name)?;
+    let mut peripherals = Vec::new();
+    if let Some(list) = child(root, "peripherals") {
+        for node in children(list, "peripheral") {
+            peripherals.push(parse_peripheral(node, properties, &name)?);
+        }
+    }
+
+    Ok(Device { name, peripherals })
+}
+
+fn parse_peripheral(node: Node, properties: Properties, path: &str) -> Result<Peripheral, Error> {
+    let name = strip_dim(required(node, "name", path)?);
+    let properties = properties.inherit(node, &name)?;
+    let items = match child(node, "registers") {
+        Some(list) => parse_items(list, properties, &name)?,
+        None => Vec::new(),
+    };
+
+    Ok(Peripheral {
+        description: description(node),
+        derived_from: node.attribute("derivedFrom").map(String::from),
+        name,
+        items,
+    })
+}
+
+fn parse_items(list: Node, properties: Properties, path: &str) -> Result<Vec<Item>, Error> {
+    let mut items = Vec::new();
+    for node in list.children() {
+        if node.has_tag_name("register") {
+            items.push(Item::Register(parse_register(node, properties, path)?));
+        } else if node.has_tag_name("cluster") {
+            items.push(Item::Cluster(parse_cluster(node, properties, path)?));
+        }
+    }
+    Ok(items)
+}
+
+fn parse_cluster(node: Node, properties: Properties, path: &str) -> Result<Cluster, Error> {
+    let name = strip_dim(required(node, "name", path)?);
+    let path = format!("{}.{}", path, name);
+    let properties = properties.inherit(node, &path)?;
+
+    Ok(Cluster {
+        description: description(node),
+        items: parse_items(node, properties, &path)?,
+        name,
+    })
+}
+
+fn parse_register(node: Node, properties: Properties, path: &str) -> Result<Register, Error> {
+    let name = strip_dim(required(node, "name", path)?);
+    let path = format!("{}.{}", path, name);
+    let properties = properties.inherit(node, &path)?;
+
+    let size = match properties.size.unwrap_or(32) {
+        size @ (8 | 16 | 32 | 64) => size as u32,
+        size => return Err(Error::svd(&path, format!("unsupported register size of {} bits", size))),
+    };
+    let access = properties.access.unwrap_or(Access::ReadWrite);
+    let reset_value = properties
+        .reset_value
+        .map(|value| value & properties.reset_mask.unwrap_or(u64::MAX) & mask(size));
+
+    let mut fields = Vec::new();
+    if let Some(list) = child(node, "fields") {
+        let nodes: Vec<Node> = children(list, "field").collect();
+        for field in &nodes {
+            fields.extend(parse_field(*field, &nodes, access, size, &path)?);
+        }
+    }
+
+    Ok(Register {
+        description: description(node),
+        derived_from: node.attribute("derivedFrom").map(String::from),
+        name,
+        size,
+        access,
+        reset_value,
+        fields,
+    })
+}
+
+fn parse_field(node: Node, siblings: &[Node], access: Access, size: u32, path: &str) -> Result<Vec<Field>, Error> {
+    let name = required(node, "name", path)?;
+    let path = format!("{}.{}", path, name);
+    let (msb, lsb) = bit_range(node, &path)?;
+    let access = match child_text(node, "access") {
+        Some(access) => access_from(access, &path)?,
+        None => access,
+    };
+    let enumeration = match child(node, "enumeratedValues") {
+        Some(values) => Some(Enumeration {
+            field: strip_dim(name),
+            values: parse_values(values, siblings, msb - lsb + 1, &path)?,
+        })
+        .filter(|enumeration| !enumeration.values.is_empty()),
+        None => None,
+    };
+
+    // field arrays are expanded into individual fields, as their bit ranges differ
+    let instances = match dim(node, &path)? {
+        Some((indices, increment)) => indices
+            .iter()
+            .enumerate()
+            .map(|(position, index)| (name.replace("%s", index), position as u64 * increment))
+            .collect(),
+        None => vec![(name.to_string(), 0)],
+    };
+
+    let mut fields = Vec::new();
+    for (name, offset) in instances {
+        let (msb, lsb) = (msb as u64 + offset, lsb as u64 + offset);
+        if msb >= size as u64 {
+            return Err(Error::svd(
+                &path,
+                format!("bit range {}:{} exceeds register size of {} bits", msb, lsb, size),
+            ));
+        }
+
+        fields.push(Field {
+            name,
+            description: description(node),
+            msb: msb as u32,
+            lsb: lsb as u32,
+            access,
+            enumeration: enumeration.clone(),
+        });
+    }
+    Ok(fields)
+}
+
+fn parse_values(node: Node, siblings: &[Node], width: u32, path: &str) -> Result<Vec<Value>, Error> {
+    let node = match node.attribute("derivedFrom") {
+        Some(name) => find_values(siblings, name)
+            .ok_or_else(|| Error::svd(path, format!("unknown enumerated values `{}`", name)))?,
+        None => node,
+    };
+
+    let mut values: Vec<Value> = Vec::new();
+    for entry in children(node, "enumeratedValue") {
+        let name = required(entry, "name", path)?;
+        let text = match child_text(entry, "value") {
+            Some(text) => text,
+            // entries marked with isDefault cover all remaining values
+            None => continue,
+        };
+        if text.starts_with('#') && text.contains(['x', 'X']) {
+            // values with don't care bits can not be represented by a single variant
+            continue;
+        }
+
+        let value = number(text, &format!("{}.{}", path, name))?;
+        if value > mask(width) {
+            return Err(Error::svd(
+                path,
+                format!("enumerated value `{}` exceeds the field width", name),
+            ));
+        }
+        if values.iter().all(|other| other.value != value) {
+            values.push(Value {
+                name: name.to_string(),
+                description: description(entry),
+                value,
+            });
+        }
+    }
+    Ok(values)
+}
+
+/// Find enumerated values referenced by `derivedFrom` within the fields of the same register.
+fn find_values<'a, 'input>(fields: &[Node<'a, 'input>], name: &str) -> Option<Node<'a, 'input>> {
+    let name = name.rsplit('.').next().unwrap_or(name);
+    fields
+        .iter()
+        .flat_map(|field| children(*field, "enumeratedValues"))
+        .find(|values| child_text(*values, "name") == Some(name))
+}
+
+fn bit_range(node: Node, path: &str) -> Result<(u32, u32), Error> {
+    let (msb, lsb) = if let Some(offset) = optional(node, "bitOffset", path)? {
+        match optional(node, "bitWidth", path)?.unwrap_or(1) {
+            0 => return Err(Error::svd(path, "bit width must not be zero")),
+            width => (offset.saturating_add(width - 1), offset),
+        }
+    } else if let (Some(msb), Some(lsb)) = (optional(node, "msb", path)?, optional(node, "lsb", path)?) {
+        (msb, lsb)
+    } else if let Some(range) = child_text(node, "bitRange") {
+        let (msb, lsb) = range
+            .strip_prefix('[')
+            .and_then(|range| range.strip_suffix(']'))
+            .and_then(|range| range.split_once(':'))
+            .ok_or_else(|| Error::svd(path, format!("invalid bit range `{}`", range)))?;
+        (number(msb, path)?, number(lsb, path)?)
+    } else {
+        return Err(Error::svd(path, "missing bit range"));
+    };
+
+    if msb < lsb {
+        return Err(Error::svd(path, format!("msb {} is below lsb {}", msb, lsb)));
+    }
+    if msb >= 64 {
+        return Err(Error::svd(path, format!("msb {} exceeds 64 bits", msb)));
+    }
+    Ok((msb as u32, lsb as u32))
+}
+
+/// Parse the indices and increment of an element array, if the element is one.
+fn dim(node: Node, path: &str) -> Result<Option<(Vec<String>, u64)>, Error> {
+    let count = match optional(node, "dim", path)? {
+        Some(count) => count,
+        None => return Ok(None),
+    };
+    let increment = optional(node, "dimIncrement", path)?
+        .ok_or_else(|| Error::svd(path, "missing `dimIncrement` of array"))?;
+
+    let indices: Vec<String> = match child_text(node, "dimIndex") {
+        Some(text) => match text.split_once('-') {
+            Some((first, last)) => match (first.trim().parse::<u64>(), last.trim().parse::<u64>()) {
+                (Ok(first), Ok(last)) => (first..=last).map(|index| index.to_string()).collect(),
+                _ => match (first.trim().parse::<char>(), last.trim().parse::<char>()) {
+                    (Ok(first), Ok(last)) => (first..=last).map(String::from).collect(),
+                    _ => return Err(Error::svd(path, format!("invalid `dimIndex` of `{}`", text))),
+                },
+            },
+            None => text.split(',').map(|index| index.trim().to_string()).collect(),
+        },
+        None => (0..count).map(|index| index.to_string()).collect(),
+    };
+
+    if indices.len() as u64 != count {
+        return Err(Error::svd(path, format!("`dimIndex` does not contain {} indices", count)));
+    }
+    Ok(Some((indices, increment)))
+}
+
+fn access_from(text: &str, path: &str) -> Result<Access, Error> {
+    match text {
+        "read-only" => Ok(Access::Read),
+        "write-only" | "writeOnce" => Ok(Access::Write),
+        "read-write" | "read-writeOnce" => Ok(Access::ReadWrite),
+        _ => Err(Error::svd(path, format!("invalid access `{}`", text))),
+    }
+}
+
+fn number(text: &str, path: &str) -> Result<u64, Error> {
+    let text = text.trim();
+    let result = if let Some(digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        u64::from_str_radix(digits, 16)
+    } else if let Some(digits) = text.strip_prefix("0b").or_else(|| text.strip_prefix('#')) {
+        u64::from_str_radix(digits, 2)
+    } else {
+        text.parse()
+    };
+    result.map_err(|_| Error::svd(path, format!("invalid number `{}`", text)))
+}
+
+fn mask(width: u32) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Remove the placeholder of arrays from a name, as all elements share a single definition.
+fn strip_dim(name: &str) -> String {
+    name.replace("[%s]", "").replace("%s", "")
+}
+
+fn description(node: Node) -> Option<String> {
+    child_text(node, "description")
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty())
+}
+
+fn required<'a>(node: Node<'a, '_>, tag: &str, path: &str) -> Result<&'a str, Error> {
+    child_text(node, tag).ok_or_else(|| Error::svd(path, format!("missing `{}`", tag)))
+}
+
+fn optional(node: Node, tag: &str, path: &str) -> Result<Option<u64>, Error> {
+    child_text(node, tag).map(|text| number(text, path)).transpose()
+}
+
+fn child_text<'a>(node: Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    child(node, tag).and_then(|node| node.text()).map(str::trim)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(tag))
+}
+
+fn children<'a, 'input: 'a>(node: Node<'a, 'input>, tag: &'a str) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |child| child.has_tag_name(tag))
+}
@@ -0,0 +1,113 @@
+#![deny(missing_docs)]
+
+//! This crate generates `bitfield!` definitions for the registers of a device described by a
+//! CMSIS-SVD file. It is meant to be used from a build script, writing the generated source into
+//! `OUT_DIR` so it can be included by the crate being built:
+//!
+//! ```rust,no_run
+//! // build.rs
+//! let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+//! const_bitfield_svd::generate_file("device.svd", out_dir.join("registers.rs")).unwrap();
+//! ```
+//!
+//! ```rust,ignore
+//! // src/lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/registers.rs"));
+//! ```
+//!
+//! Every peripheral becomes a module named after it, containing one bitfield struct per register.
+//! Clusters become nested modules, while peripherals and registers using `derivedFrom` re-use the
+//! definitions they are derived from. Register arrays share a single definition, whereas field
+//! arrays are expanded into individual fields.
+//!
+//! - Fields are generated with getters for readable and setters for writable access rights,
+//!   using `bool` for single bits and the smallest fitting unsigned integer otherwise.
+//! - Registers without any fields get a single `value` field covering the whole register.
+//! - Overlapping fields are marked with `overlap`.
+//! - Enumerated values become an enum per field, named after register and field. The field is
+//!   set using the enum and read as a `Result`, returning the raw value if it is not enumerated.
+//! - Reset values are available as `RESET_VALUE` constant and through `Default`.
+//!
+//! The conversions of enumerated fields are implemented as regular, non-const traits. Crates
+//! using the generated source on Rust nightly must therefore enable `const_convert` in addition
+//! to the feature flags documented in `const-bitfield`.
+
+mod device;
+mod render;
+
+use std::path::Path;
+use std::{error, fmt, fs, io};
+
+/// Generate the source of all register definitions for the given SVD document.
+pub fn generate(svd: &str) -> Result<String, Error> {
+    let device = device::parse(svd)?;
+    Ok(render::render(&device))
+}
+
+/// Generate the register definitions for the SVD file at `input` and write them to `output`.
+/// Instructs cargo to re-run the build script whenever the SVD file changes.
+pub fn generate_file(input: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<(), Error> {
+    let input = input.as_ref();
+    println!("cargo:rerun-if-changed={}", input.display());
+
+    let svd = fs::read_to_string(input)?;
+    fs::write(output, generate(&svd)?)?;
+    Ok(())
+}
+
+/// Errors occurring while generating register definitions.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the SVD file or writing the generated source failed.
+    Io(io::Error),
+    /// The SVD file is not a well-formed XML document.
+    Xml(roxmltree::Error),
+    /// An element of the SVD file is missing or invalid.
+    Svd {
+        /// Path of the offending element, such as `TIMER0.CTRL.MODE`.
+        path: String,
+        /// Description of the problem.
+        message: String,
+    },
+}
+
+impl Error {
+    pub(crate) fn svd(path: &str, message: impl Into<String>) -> Self {
+        Error::Svd {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "i/o error: {}", error),
+            Error::Xml(error) => write!(f, "invalid xml: {}", error),
+            Error::Svd { path, message } => write!(f, "`{}`: {}", path, message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Xml(error) => Some(error),
+            Error::Svd { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<roxmltree::Error> for Error {
+    fn from(error: roxmltree::Error) -> Self {
+        Error::Xml(error)
+    }
+}
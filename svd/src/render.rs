@@ -0,0 +1,308 @@
+//! Rendering of the device model into Rust source using the `bitfield!` macro.
+
+use crate::device::{Device, Enumeration, Field, Item, Peripheral, Register};
+use std::collections::HashSet;
+
+/// Reserved words which can not be used as identifiers and get an underscore appended.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct",
+    "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+    "while", "yield",
+];
+
+pub(crate) fn render(device: &Device) -> String {
+    let mut writer = Writer::default();
+    writer.line(format!(
+        "// Generated by const-bitfield-svd from the description of `{}`, do not edit.",
+        device.name
+    ));
+    for peripheral in &device.peripherals {
+        writer.separate();
+        render_peripheral(&mut writer, peripheral);
+    }
+    writer.source
+}
+
+fn render_peripheral(writer: &mut Writer, peripheral: &Peripheral) {
+    writer.docs(&peripheral.description);
+    writer.open(format!("pub mod {} {{", snake(&peripheral.name)));
+    match &peripheral.derived_from {
+        Some(base) => writer.line(format!("pub use super::{}::*;", snake(base))),
+        None => render_items(writer, &peripheral.items),
+    }
+    writer.close("}");
+}
+
+fn render_items(writer: &mut Writer, items: &[Item]) {
+    // elements of register and cluster arrays share a single definition
+    let mut names = HashSet::new();
+    for item in items {
+        match item {
+            Item::Register(register) if names.insert(camel(&register.name)) => {
+                writer.separate();
+                render_register(writer, register);
+            }
+            Item::Cluster(cluster) if names.insert(snake(&cluster.name)) => {
+                writer.separate();
+                writer.docs(&cluster.description);
+                writer.open(format!("pub mod {} {{", snake(&cluster.name)));
+                render_items(writer, &cluster.items);
+                writer.close("}");
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render_register(writer: &mut Writer, register: &Register) {
+    let name = camel(&register.name);
+    if let Some(base) = &register.derived_from {
+        writer.docs(&register.description);
+        writer.line(format!("pub type {} = {};", name, camel(base.rsplit('.').next().unwrap_or(base))));
+        return;
+    }
+
+    let storage = format!("u{}", register.size);
+    writer.open("::const_bitfield::bitfield! {");
+    writer.docs(&register.description);
+    writer.line("#[derive(Copy, Clone, Eq, PartialEq)]");
+    writer.line(format!("pub struct {}({});", name, storage));
+    writer.line("impl Debug;");
+    if register.fields.is_empty() {
+        let getter = if register.access.is_readable() { "value" } else { "_" };
+        let setter = if register.access.is_writable() { "set_value" } else { "_" };
+        writer.line(format!("pub {}, {}, {}: {}, 0;", storage, getter, setter, register.size - 1));
+    }
+    for field in &register.fields {
+        render_field(writer, register, &name, field);
+    }
+    writer.close("}");
+
+    if let Some(reset_value) = register.reset_value {
+        writer.separate();
+        writer.open(format!("impl {} {{", name));
+        writer.line("/// Value of the register after reset.");
+        writer.line(format!(
+            "pub const RESET_VALUE: {} = {:#0width$x};",
+            storage,
+            reset_value,
+            width = register.size as usize / 4 + 2
+        ));
+        writer.close("}");
+
+        writer.separate();
+        writer.open(format!("impl Default for {} {{", name));
+        writer.open("fn default() -> Self {");
+        writer.line("Self(Self::RESET_VALUE)");
+        writer.close("}");
+        writer.close("}");
+    }
+
+    let mut enumerations = HashSet::new();
+    for field in &register.fields {
+        if let Some(enumeration) = &field.enumeration {
+            if enumerations.insert(&enumeration.field) {
+                writer.separate();
+                render_enumeration(writer, register, &name, field, enumeration);
+            }
+        }
+    }
+}
+
+fn render_field(writer: &mut Writer, register: &Register, register_name: &str, field: &Field) {
+    let overlap = register
+        .fields
+        .iter()
+        .any(|other| !std::ptr::eq(other, field) && other.lsb <= field.msb && field.lsb <= other.msb);
+    let getter = if field.access.is_readable() { snake(&field.name) } else { "_".into() };
+    let setter = if field.access.is_writable() { format!("set_{}", words(&field.name).join("_")) } else { "_".into() };
+    let conversion = match &field.enumeration {
+        Some(enumeration) => {
+            let name = format!("{}{}", register_name, camel(&enumeration.field));
+            match (field.access.is_readable(), field.access.is_writable()) {
+                (true, true) => format!("from {}, try_into {}, ", name, name),
+                (true, false) => format!("try_into {}, ", name),
+                _ => format!("from {}, ", name),
+            }
+        }
+        None => String::new(),
+    };
+    let bits = match field.msb == field.lsb {
+        true => field.lsb.to_string(),
+        false => format!("{}, {}", field.msb, field.lsb),
+    };
+
+    writer.docs(&field.description);
+    writer.line(format!(
+        "pub {}{}, {}{}, {}: {};",
+        if overlap { "overlap " } else { "" },
+        raw_type(field.msb - field.lsb + 1),
+        conversion,
+        getter,
+        setter,
+        bits
+    ));
+}
+
+fn render_enumeration(
+    writer: &mut Writer,
+    register: &Register,
+    register_name: &str,
+    field: &Field,
+    enumeration: &Enumeration,
+) {
+    let name = format!("{}{}", register_name, camel(&enumeration.field));
+    let raw = raw_type(field.msb - field.lsb + 1);
+    let literal = |value: u64| match raw {
+        "bool" => (value != 0).to_string(),
+        _ => format!("{:#x}", value),
+    };
+
+    let mut variants: Vec<String> = Vec::new();
+    for value in &enumeration.values {
+        let mut variant = camel(&value.name);
+        if variants.contains(&variant) {
+            variant.push_str(&value.value.to_string());
+        }
+        variants.push(variant);
+    }
+
+    writer.line(format!(
+        "/// Enumerated values of field `{}` of register `{}`.",
+        enumeration.field, register.name
+    ));
+    writer.line("#[derive(Copy, Clone, Debug, Eq, PartialEq)]");
+    writer.open(format!("pub enum {} {{", name));
+    for (variant, value) in variants.iter().zip(&enumeration.values) {
+        writer.docs(&value.description);
+        writer.line(format!("{},", variant));
+    }
+    writer.close("}");
+
+    writer.separate();
+    writer.open(format!("impl TryFrom<{}> for {} {{", raw, name));
+    writer.line(format!("type Error = {};", raw));
+    writer.line("");
+    writer.open(format!("fn try_from(value: {}) -> Result<Self, Self::Error> {{", raw));
+    writer.open("match value {");
+    for (variant, value) in variants.iter().zip(&enumeration.values) {
+        writer.line(format!("{} => Ok(Self::{}),", literal(value.value), variant));
+    }
+    let bits = if raw == "bool" { 1 } else { raw[1..].parse().unwrap_or(64) };
+    if bits >= 64 || enumeration.values.len() as u64 != 1 << bits {
+        writer.line("_ => Err(value),");
+    }
+    writer.close("}");
+    writer.close("}");
+    writer.close("}");
+
+    writer.separate();
+    writer.open(format!("impl From<{}> for {} {{", name, raw));
+    writer.open(format!("fn from(value: {}) -> Self {{", name));
+    writer.open("match value {");
+    for (variant, value) in variants.iter().zip(&enumeration.values) {
+        writer.line(format!("{}::{} => {},", name, variant, literal(value.value)));
+    }
+    writer.close("}");
+    writer.close("}");
+    writer.close("}");
+}
+
+/// Smallest value type able to hold a field of the given width.
+fn raw_type(width: u32) -> &'static str {
+    match width {
+        1 => "bool",
+        2..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        _ => "u64",
+    }
+}
+
+/// Split a name into its words at non-alphanumeric characters and lower to upper case changes.
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut lower = false;
+    for char in name.chars() {
+        let boundary = !char.is_ascii_alphanumeric() || (lower && char.is_ascii_uppercase());
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if char.is_ascii_alphanumeric() {
+            word.push(char.to_ascii_lowercase());
+        }
+        lower = char.is_ascii_lowercase();
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn snake(name: &str) -> String {
+    let mut ident = words(name).join("_");
+    if ident.is_empty() || ident.starts_with(|char: char| char.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+fn camel(name: &str) -> String {
+    let mut ident = String::new();
+    for word in words(name) {
+        let mut chars = word.chars();
+        ident.extend(chars.next().map(|char| char.to_ascii_uppercase()));
+        ident.extend(chars);
+    }
+    if ident.is_empty() || ident.starts_with(|char: char| char.is_ascii_digit()) {
+        ident.insert(0, 'V');
+    }
+    ident
+}
+
+/// Line-based writer of the generated source, indenting nested blocks by four spaces.
+#[derive(Default)]
+struct Writer {
+    source: String,
+    depth: usize,
+}
+
+impl Writer {
+    fn line(&mut self, line: impl AsRef<str>) {
+        let line = line.as_ref();
+        if !line.is_empty() {
+            self.source.push_str(&"    ".repeat(self.depth));
+            self.source.push_str(line);
+        }
+        self.source.push('\n');
+    }
+
+    fn open(&mut self, line: impl AsRef<str>) {
+        self.line(line);
+        self.depth += 1;
+    }
+
+    fn close(&mut self, line: impl AsRef<str>) {
+        self.depth -= 1;
+        self.line(line);
+    }
+
+    fn docs(&mut self, description: &Option<String>) {
+        if let Some(description) = description {
+            self.line(format!("/// {}", description));
+        }
+    }
+
+    /// Separate the next item from the previous one by a blank line, unless a block was just opened.
+    fn separate(&mut self) {
+        if !self.source.is_empty() && !self.source.ends_with("{\n") {
+            self.line("");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// Generated by const-bitfield-svd from the description of `ARRAYS`, do not edit.
+
+/// General purpose I/O
+pub mod gpio {
+    ::const_bitfield::bitfield! {
+        /// Direction of the pins
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Dir(u8);
+        impl Debug;
+        /// Direction of the pin
+        pub u8, from DirPin, try_into DirPin, pin0, set_pin0: 1, 0;
+        /// Direction of the pin
+        pub u8, from DirPin, try_into DirPin, pin1, set_pin1: 3, 2;
+        /// Direction of the pin
+        pub u8, from DirPin, try_into DirPin, pin2, set_pin2: 5, 4;
+        /// Direction of the pin
+        pub u8, from DirPin, try_into DirPin, pin3, set_pin3: 7, 6;
+    }
+
+    impl Dir {
+        /// Value of the register after reset.
+        pub const RESET_VALUE: u8 = 0x55;
+    }
+
+    impl Default for Dir {
+        fn default() -> Self {
+            Self(Self::RESET_VALUE)
+        }
+    }
+
+    /// Enumerated values of field `PIN` of register `DIR`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum DirPin {
+        Input,
+        Output,
+        Analog,
+        Alternate,
+    }
+
+    impl TryFrom<u8> for DirPin {
+        type Error = u8;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                0x0 => Ok(Self::Input),
+                0x1 => Ok(Self::Output),
+                0x2 => Ok(Self::Analog),
+                0x3 => Ok(Self::Alternate),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<DirPin> for u8 {
+        fn from(value: DirPin) -> Self {
+            match value {
+                DirPin::Input => 0x0,
+                DirPin::Output => 0x1,
+                DirPin::Analog => 0x2,
+                DirPin::Alternate => 0x3,
+            }
+        }
+    }
+
+    ::const_bitfield::bitfield! {
+        /// Output level of a port
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Out(u8);
+        impl Debug;
+        pub u8, value, set_value: 7, 0;
+    }
+
+    /// Direction of the pins, updated atomically
+    pub type DirSet = Dir;
+
+    /// Channel
+    pub mod ch {
+        ::const_bitfield::bitfield! {
+            /// DMA configuration
+            #[derive(Copy, Clone, Eq, PartialEq)]
+            pub struct DmaCfg(u16);
+            impl Debug;
+            pub bool, rx_enable, set_rx_enable: 0;
+            pub overlap bool, tx_enable, set_tx_enable: 8;
+            /// Whole configuration byte
+            pub overlap u8, word, set_word: 15, 8;
+            /// Trigger level
+            pub u8, from DmaCfgLevel, try_into DmaCfgLevel, level, set_level: 3, 1;
+            /// Minimum trigger level
+            pub overlap u8, from DmaCfgMinLevel, try_into DmaCfgMinLevel, min_level, set_min_level: 12, 10;
+        }
+
+        /// Enumerated values of field `LEVEL` of register `dmaCfg`.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+        pub enum DmaCfgLevel {
+            V2Bytes,
+            V4Bytes,
+        }
+
+        impl TryFrom<u8> for DmaCfgLevel {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    0x1 => Ok(Self::V2Bytes),
+                    0x2 => Ok(Self::V4Bytes),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        impl From<DmaCfgLevel> for u8 {
+            fn from(value: DmaCfgLevel) -> Self {
+                match value {
+                    DmaCfgLevel::V2Bytes => 0x1,
+                    DmaCfgLevel::V4Bytes => 0x2,
+                }
+            }
+        }
+
+        /// Enumerated values of field `MIN_LEVEL` of register `dmaCfg`.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+        pub enum DmaCfgMinLevel {
+            V2Bytes,
+            V4Bytes,
+        }
+
+        impl TryFrom<u8> for DmaCfgMinLevel {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    0x1 => Ok(Self::V2Bytes),
+                    0x2 => Ok(Self::V4Bytes),
+                    _ => Err(value),
+                }
+            }
+        }
+
+        impl From<DmaCfgMinLevel> for u8 {
+            fn from(value: DmaCfgMinLevel) -> Self {
+                match value {
+                    DmaCfgMinLevel::V2Bytes => 0x1,
+                    DmaCfgMinLevel::V4Bytes => 0x2,
+                }
+            }
+        }
+    }
+}
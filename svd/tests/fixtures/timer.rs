@@ -0,0 +1,184 @@
+// Generated by const-bitfield-svd from the description of `TIMERS`, do not edit.
+
+/// General purpose timer
+pub mod timer0 {
+    ::const_bitfield::bitfield! {
+        /// Control register
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Ctrl(u32);
+        impl Debug;
+        /// Counter enable
+        pub bool, from CtrlEn, try_into CtrlEn, en, set_en: 0;
+        /// Counting mode
+        pub u8, from CtrlMode, try_into CtrlMode, mode, set_mode: 2, 1;
+        /// Prescaler of the input clock
+        pub u8, prescale, set_prescale: 15, 8;
+        /// Counter type
+        pub bool, type_, _: 16;
+    }
+
+    impl Ctrl {
+        /// Value of the register after reset.
+        pub const RESET_VALUE: u32 = 0x00000100;
+    }
+
+    impl Default for Ctrl {
+        fn default() -> Self {
+            Self(Self::RESET_VALUE)
+        }
+    }
+
+    /// Enumerated values of field `EN` of register `CTRL`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum CtrlEn {
+        /// Counter is stopped
+        Disabled,
+        /// Counter is running
+        Enabled,
+    }
+
+    impl TryFrom<bool> for CtrlEn {
+        type Error = bool;
+
+        fn try_from(value: bool) -> Result<Self, Self::Error> {
+            match value {
+                false => Ok(Self::Disabled),
+                true => Ok(Self::Enabled),
+            }
+        }
+    }
+
+    impl From<CtrlEn> for bool {
+        fn from(value: CtrlEn) -> Self {
+            match value {
+                CtrlEn::Disabled => false,
+                CtrlEn::Enabled => true,
+            }
+        }
+    }
+
+    /// Enumerated values of field `MODE` of register `CTRL`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum CtrlMode {
+        OneShot,
+        Periodic,
+        Continuous,
+    }
+
+    impl TryFrom<u8> for CtrlMode {
+        type Error = u8;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                0x0 => Ok(Self::OneShot),
+                0x1 => Ok(Self::Periodic),
+                0x2 => Ok(Self::Continuous),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<CtrlMode> for u8 {
+        fn from(value: CtrlMode) -> Self {
+            match value {
+                CtrlMode::OneShot => 0x0,
+                CtrlMode::Periodic => 0x1,
+                CtrlMode::Continuous => 0x2,
+            }
+        }
+    }
+
+    ::const_bitfield::bitfield! {
+        /// Status register
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Status(u16);
+        impl Debug;
+        /// Counter is busy
+        pub bool, busy, _: 0;
+        /// Counter overflowed, write 1 to clear
+        pub bool, ovf, set_ovf: 1;
+        /// Upper bits of the counter
+        pub u16, count, _: 15, 4;
+    }
+
+    impl Status {
+        /// Value of the register after reset.
+        pub const RESET_VALUE: u16 = 0x0000;
+    }
+
+    impl Default for Status {
+        fn default() -> Self {
+            Self(Self::RESET_VALUE)
+        }
+    }
+
+    ::const_bitfield::bitfield! {
+        /// Reload value
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Load(u32);
+        impl Debug;
+        pub u32, value, set_value: 31, 0;
+    }
+
+    impl Load {
+        /// Value of the register after reset.
+        pub const RESET_VALUE: u32 = 0xffffffff;
+    }
+
+    impl Default for Load {
+        fn default() -> Self {
+            Self(Self::RESET_VALUE)
+        }
+    }
+
+    ::const_bitfield::bitfield! {
+        /// Command register
+        #[derive(Copy, Clone, Eq, PartialEq)]
+        pub struct Cmd(u32);
+        impl Debug;
+        pub u8, from CmdAction, _, set_action: 1, 0;
+    }
+
+    impl Cmd {
+        /// Value of the register after reset.
+        pub const RESET_VALUE: u32 = 0x00000000;
+    }
+
+    impl Default for Cmd {
+        fn default() -> Self {
+            Self(Self::RESET_VALUE)
+        }
+    }
+
+    /// Enumerated values of field `ACTION` of register `CMD`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum CmdAction {
+        Start,
+        Stop,
+    }
+
+    impl TryFrom<u8> for CmdAction {
+        type Error = u8;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                0x1 => Ok(Self::Start),
+                0x2 => Ok(Self::Stop),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<CmdAction> for u8 {
+        fn from(value: CmdAction) -> Self {
+            match value {
+                CmdAction::Start => 0x1,
+                CmdAction::Stop => 0x2,
+            }
+        }
+    }
+}
+
+pub mod timer1 {
+    pub use super::timer0::*;
+}
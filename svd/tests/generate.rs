@@ -0,0 +1,73 @@
+use const_bitfield_svd::{generate, generate_file, Error};
+use std::{env, fs};
+
+#[test]
+pub fn test_generate_fixtures() {
+    for name in ["timer", "arrays"] {
+        let svd = fs::read_to_string(format!("tests/fixtures/{}.svd", name)).unwrap();
+        let expected = fs::read_to_string(format!("tests/fixtures/{}.rs", name)).unwrap();
+        assert_eq!(generate(&svd).unwrap(), expected, "generated source of `{}` differs", name);
+    }
+}
+
+#[test]
+pub fn test_generate_file() {
+    let output = env::temp_dir().join("const-bitfield-svd-timer.rs");
+    generate_file("tests/fixtures/timer.svd", &output).unwrap();
+    assert_eq!(
+        fs::read_to_string(&output).unwrap(),
+        fs::read_to_string("tests/fixtures/timer.rs").unwrap()
+    );
+
+    let error = generate_file("tests/fixtures/missing.svd", &output).unwrap_err();
+    assert!(matches!(error, Error::Io(_)));
+}
+
+#[test]
+pub fn test_generate_invalid() {
+    let svd = |fields: &str| {
+        format!(
+            "<device><name>DEV</name><peripherals><peripheral><name>P</name><registers>\
+             <register><name>R</name><size>8</size><fields>{}</fields></register>\
+             </registers></peripheral></peripherals></device>",
+            fields
+        )
+    };
+
+    let error = generate("<device>").unwrap_err();
+    assert!(matches!(error, Error::Xml(_)));
+
+    let error = generate("<peripherals/>").unwrap_err();
+    assert_eq!(error.to_string(), "`peripherals`: root element must be `device`");
+
+    let error = generate(&svd("<field><name>F</name></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: missing bit range");
+
+    let error = generate(&svd("<field><name>F</name><bitRange>[1:2]</bitRange></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: msb 1 is below lsb 2");
+
+    let error = generate(&svd("<field><name>F</name><lsb>4</lsb><msb>8</msb></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: bit range 8:4 exceeds register size of 8 bits");
+
+    let error = generate(&svd("<field><name>F</name><bitOffset>0x1G</bitOffset></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: invalid number `0x1G`");
+
+    let error = generate(&svd("<field><name>F</name><bitOffset>0</bitOffset><access>none</access></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: invalid access `none`");
+
+    let error = generate(&svd(
+        "<field><name>F</name><bitOffset>0</bitOffset><enumeratedValues derivedFrom=\"X\"/></field>",
+    ))
+    .unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: unknown enumerated values `X`");
+
+    let error = generate(&svd(
+        "<field><name>F</name><bitOffset>0</bitOffset><enumeratedValues>\
+         <enumeratedValue><name>V</name><value>2</value></enumeratedValue></enumeratedValues></field>",
+    ))
+    .unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F`: enumerated value `V` exceeds the field width");
+
+    let error = generate(&svd("<field><dim>2</dim><name>F%s</name><bitOffset>0</bitOffset></field>")).unwrap_err();
+    assert_eq!(error.to_string(), "`P.R.F%s`: missing `dimIncrement` of array");
+}
@@ -0,0 +1,66 @@
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+mod timers {
+    include!("fixtures/timer.rs");
+}
+
+mod arrays {
+    include!("fixtures/arrays.rs");
+}
+
+use arrays::gpio::{self, ch::DmaCfg, ch::DmaCfgLevel, DirPin};
+use timers::timer0::{Cmd, CmdAction, Ctrl, CtrlEn, CtrlMode, Load, Status};
+
+#[test]
+pub fn test_registers_fields() {
+    let mut ctrl = Ctrl::default();
+    assert_eq!(ctrl.0, 0x100);
+    assert_eq!(ctrl.en(), Ok(CtrlEn::Disabled));
+    assert_eq!(ctrl.mode(), Ok(CtrlMode::OneShot));
+    assert_eq!(ctrl.prescale(), 1);
+    assert!(!ctrl.type_());
+
+    ctrl.set_en(CtrlEn::Enabled).set_mode(CtrlMode::Continuous).set_prescale(0x20);
+    assert_eq!(ctrl.0, 0x2005);
+    assert_eq!(Ctrl(0x6).mode(), Err(0x3));
+
+    let status = Status(0xABC3);
+    assert!(status.busy());
+    assert!(status.ovf());
+    assert_eq!(status.count(), 0xABC);
+
+    assert_eq!(Load::RESET_VALUE, 0xFFFF_FFFF);
+    assert_eq!(Load::default().value(), 0xFFFF_FFFF);
+
+    let mut cmd = Cmd::default();
+    cmd.set_action(CmdAction::Stop);
+    assert_eq!(cmd.0, 0x2);
+    assert_eq!(CmdAction::try_from(0x1), Ok(CmdAction::Start));
+}
+
+#[test]
+pub fn test_registers_derived() {
+    let ctrl: timers::timer1::Ctrl = timers::timer0::Ctrl::default();
+    assert_eq!(ctrl.0, 0x100);
+
+    let mut dir = gpio::DirSet::default();
+    assert_eq!(dir.pin0(), Ok(DirPin::Output));
+    dir.set_pin3(DirPin::Alternate).set_pin1(DirPin::Input);
+    assert_eq!(dir.0, 0xD1);
+    assert_eq!(gpio::Out(0x5A).value(), 0x5A);
+}
+
+#[test]
+pub fn test_registers_cluster() {
+    let mut cfg = DmaCfg(0);
+    cfg.set_rx_enable(true).set_level(DmaCfgLevel::V4Bytes).set_word(0x81);
+    assert_eq!(cfg.0, 0x8105);
+    assert!(cfg.tx_enable());
+    assert_eq!(cfg.min_level(), Err(0));
+    assert_eq!(
+        format!("{:?}", cfg),
+        "DmaCfg { raw: 0x8105, rx_enable: true, tx_enable: true, word: 129, level: Ok(V4Bytes), min_level: Err(0) }"
+    );
+}
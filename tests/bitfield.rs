@@ -1,7 +1,7 @@
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
-use const_bitfield::bitfield;
+use const_bitfield::{bitfield, BitfieldError};
 use std::panic;
 
 bitfield! {
@@ -13,6 +13,83 @@ bitfield! {
     u8, f5, set_f5: 127, 120;
 }
 
+bitfield! {
+    struct Narrow(u16);
+    u8, n1, set_n1: 3, 0;
+    bool, n2, set_n2: 4;
+    u16, n3, set_n3: 15, 5;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    #[bit_order(msb0)]
+    #[derive(Debug)]
+    struct Ipv4Header(u32);
+    u8, version, set_version: 0, 3;
+    u8, ihl, set_ihl: 4, 7;
+    u8, dscp, set_dscp: 8, 13;
+    u8, ecn, set_ecn: 14, 15;
+    u16, total_length, set_total_length: 16, 31;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    struct Msb0Bits([u8; 2]);
+    bool, first, set_first: 0;
+    bool, last, set_last: 15;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    struct Reserved(u16);
+    reserved 1: 0, 3;
+    u8, value, set_value: 4, 11;
+    reserved 0: 14;
+    reserved 1: 15;
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug)]
+    struct Control(u32);
+    u8, prescaler, set_prescaler: 7, 0 = 0x0F;
+    bool, enabled, set_enabled: 8 = true;
+    bool, interrupt, set_interrupt: 9;
+    u16, reload, _: 31, 16 = 0xFFFF;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    struct Lanes(u64);
+    u8, prio, set_prio: [8] 2, 0 stride 4;
+    bool, enabled, set_enabled: [16] 32;
+    u8, lane, set_lane: [2] 55, 48;
+}
+
+bitfield! {
+    struct DebugLanes(u16);
+    impl Debug;
+    u8, prio, set_prio: [3] 3, 0;
+    bool, _, set_flag: [2] 12;
+    bool, ready, _: 15;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    struct Msb0Lanes(u16);
+    u8, nibble, set_nibble: [4] 0, 3;
+}
+
+const NARROW: Result<u16, BitfieldError> = {
+    let mut narrow = Narrow(0);
+    match narrow.try_set_n1(0xF) {
+        Ok(_) => match narrow.try_set_n3(0x800) {
+            Ok(_) => Ok(narrow.0),
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    }
+};
+
 #[test]
 pub fn test_get_multiple() {
     let test = Test(0x55_4444444444444444_33333333_2222_11);
@@ -66,6 +143,174 @@ pub fn test_set_multiple() {
     assert_eq!((test.0 >> 120) & u8::MAX as u128, 0x55, "field 5 mismatch");
 }
 
+#[test]
+pub fn test_try_set_valid() {
+    let mut narrow = Narrow(0);
+    assert!(narrow.try_set_n1(0xF).is_ok());
+    assert!(narrow.try_set_n2(true).is_ok());
+    assert!(narrow.try_set_n3(0x7FF).is_ok());
+    assert_eq!(narrow.0, 0xFFFF);
+}
+
+#[test]
+pub fn test_try_set_truncated() {
+    let mut narrow = Narrow(0);
+    assert_eq!(narrow.try_set_n1(0x10).err(), Some(BitfieldError::ValueTruncated));
+    assert_eq!(narrow.try_set_n3(0x800).err(), Some(BitfieldError::ValueTruncated));
+    assert_eq!(narrow.0, 0);
+}
+
+#[test]
+pub fn test_try_set_const() {
+    assert_eq!(NARROW, Err(BitfieldError::ValueTruncated));
+}
+
+#[test]
+pub fn test_msb0_get() {
+    let header = Ipv4Header(0x4502_0054);
+
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.ihl(), 5);
+    assert_eq!(header.dscp(), 0);
+    assert_eq!(header.ecn(), 2);
+    assert_eq!(header.total_length(), 0x54);
+    assert_eq!(format!("{:?}", header), "Ipv4Header(1157759060)");
+}
+
+#[test]
+pub fn test_msb0_set() {
+    let mut header = Ipv4Header(0);
+    header
+        .set_version(4)
+        .set_ihl(5)
+        .set_dscp(0)
+        .set_ecn(2)
+        .set_total_length(0x54);
+
+    assert_eq!(header.0, 0x4502_0054);
+}
+
+#[test]
+pub fn test_msb0_consts() {
+    assert_eq!(Ipv4Header::VERSION_MASK, 0xF000_0000);
+    assert_eq!(Ipv4Header::VERSION_SHIFT, 28);
+    assert_eq!(Ipv4Header::TOTAL_LENGTH_MASK, 0x0000_FFFF);
+    assert_eq!(Msb0Bits::FIRST_MASK, [0x00, 0x80]);
+}
+
+#[test]
+pub fn test_msb0_bits() {
+    let mut bits = Msb0Bits([0; 2]);
+    bits.set_first(true);
+    assert_eq!(bits.0, [0x00, 0x80]);
+
+    bits.set_first(false).set_last(true);
+    assert_eq!(bits.0, [0x01, 0x00]);
+    assert!(bits.last());
+
+    // buffers in wire order start with the most significant byte
+    let bits = Msb0Bits::from_be_bytes([0x80, 0x00]);
+    assert!(bits.first());
+    assert_eq!(bits.to_be_bytes(), [0x80, 0x00]);
+}
+
+#[test]
+pub fn test_reserved() {
+    assert_eq!(Reserved::RESERVED_MASK, 0xF003);
+    assert!(Reserved(0xF001).is_valid());
+    assert!(Reserved(0xF7F1).is_valid());
+    assert!(!Reserved(0xF003).is_valid());
+    assert!(!Reserved(0x7001).is_valid());
+    assert!(!Reserved(0x0000).is_valid());
+
+    const SANITIZED: Reserved = Reserved(0x0AB2).sanitize();
+    assert_eq!(SANITIZED.0, 0xFAB1);
+    assert_eq!(SANITIZED.value(), 0xAB);
+}
+
+#[test]
+pub fn test_defaults() {
+    const CONTROL: Control = Control::new();
+    assert_eq!(CONTROL.0, 0xFFFF_010F);
+    assert_eq!(Control::default().0, 0xFFFF_010F);
+
+    let mut control = Control(0);
+    control.set_prescaler(0x42).set_interrupt(true);
+    control.reset_prescaler().reset_enabled();
+    assert_eq!(control.0, 0x0000_030F);
+}
+
+#[test]
+pub fn test_arrays() {
+    let mut lanes = Lanes(0);
+    for index in 0..Lanes::PRIO_COUNT {
+        lanes.set_prio(index, index as u8);
+    }
+    lanes.set_enabled(0, true).set_enabled(15, true).set_lane(1, 0xAB);
+    assert_eq!(lanes.0, 0xAB00_8001_7654_3210);
+
+    assert_eq!(lanes.prio(3), 3);
+    assert!(lanes.enabled(15));
+    assert!(!lanes.enabled(14));
+    assert_eq!(lanes.lane(0), 0);
+    assert_eq!(lanes.lane(1), 0xAB);
+    assert_eq!(Lanes::ENABLED_COUNT, 16);
+    assert_eq!(Lanes::LANE_COUNT, 2);
+
+    lanes.set_prio(2, 0x7);
+    assert_eq!(lanes.prio(2), 0x7);
+    assert_eq!(lanes.prio(1), 1);
+    assert_eq!(lanes.prio(3), 3);
+}
+
+#[test]
+pub fn test_arrays_out_of_bounds() {
+    let result = panic::catch_unwind(|| Lanes(0).prio(8));
+    assert!(result.is_err());
+
+    let result = panic::catch_unwind(|| Lanes(0).set_enabled(16, true).0);
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn test_arrays_debug() {
+    let lanes = DebugLanes(0x9321);
+    assert_eq!(format!("{:?}", lanes), "DebugLanes { raw: 0x9321, prio: [1, 2, 3], ready: true }");
+}
+
+#[test]
+pub fn test_msb0_arrays() {
+    let mut nibbles = Msb0Lanes(0);
+    nibbles.set_nibble(0, 0x1).set_nibble(3, 0x4);
+    assert_eq!(nibbles.0, 0x1004);
+    assert_eq!(nibbles.nibble(0), 0x1);
+    assert_eq!(Msb0Lanes(0xABCD).nibble(2), 0xC);
+}
+
+#[test]
+pub fn test_bytes_roundtrip() {
+    const RAW: u128 = 0x5544_4444_4444_4444_4433_3333_3322_2211;
+    const FROM_BE: Test = Test::from_be_bytes(RAW.to_be_bytes());
+
+    let test = Test(RAW);
+    assert_eq!(test.to_le_bytes(), RAW.to_le_bytes());
+    assert_eq!(test.to_be_bytes(), RAW.to_be_bytes());
+    assert_eq!(test.to_ne_bytes(), RAW.to_ne_bytes());
+
+    assert_eq!(Test::from_le_bytes(RAW.to_le_bytes()).0, RAW);
+    assert_eq!(Test::from_ne_bytes(RAW.to_ne_bytes()).0, RAW);
+    assert_eq!(FROM_BE.0, RAW);
+    assert_eq!(FROM_BE.f2(), 0x2222);
+}
+
+#[test]
+pub fn test_bytes_network_order() {
+    let header = Ipv4Header::from_be_bytes([0x45, 0x02, 0x00, 0x54]);
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.total_length(), 0x54);
+    assert_eq!(header.to_be_bytes(), [0x45, 0x02, 0x00, 0x54]);
+}
+
 fn get_field_max(index: usize) -> u128 {
     match index {
         1 | 5 => u8::MAX as u128,
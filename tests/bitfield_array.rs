@@ -0,0 +1,126 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+const KERNEL_TSS_BASE: u64 = 0xFFFF_8000_1234_5678;
+const KERNEL_TSS: [u64; 2] = [0x1200_8934_5678_0067, 0x0000_0000_FFFF_8000];
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct SystemDescriptor([u64; 2]);
+    impl Debug;
+    u32, limit_0_15, set_limit_0_15: 15, 0;
+    u32, base_0_23, set_base_0_23: 39, 16;
+    u8, system_type, set_system_type: 43, 40;
+    u8, privilege_level, set_privilege_level: 46, 45;
+    bool, present, set_present: 47;
+    u8, limit_16_19, set_limit_16_19: 51, 48;
+    bool, available, set_available: 52;
+    bool, granularity, set_granularity: 55;
+    u64, base_24_63, set_base_24_63: 95, 56;
+    reserved 0: 127, 96;
+}
+
+bitfield! {
+    pub struct Wide([u32; 8]);
+    u8, low, set_low: 7, 0;
+    u64, straddle, set_straddle: 95, 32;
+    overlap i16, signed, set_signed: 137, 126;
+    overlap u128, high, set_high: 255, 128;
+}
+
+#[test]
+pub fn test_parse_kernel_tss() {
+    let descriptor = SystemDescriptor(KERNEL_TSS);
+
+    assert_eq!(descriptor.limit_0_15(), 0x67);
+    assert_eq!(descriptor.limit_16_19(), 0);
+    assert_eq!(descriptor.base_0_23(), 0x34_5678);
+    assert_eq!(descriptor.base_24_63(), 0xFF_FF80_0012);
+    assert_eq!(descriptor.system_type(), 0b1001);
+    assert_eq!(descriptor.privilege_level(), 0);
+    assert!(descriptor.present());
+    assert!(!descriptor.available());
+    assert!(!descriptor.granularity());
+    assert!(descriptor.is_valid());
+}
+
+#[test]
+pub fn test_reserved_kernel_tss() {
+    let descriptor = SystemDescriptor([KERNEL_TSS[0], KERNEL_TSS[1] | 0x0000_1F00_0000_0000]);
+
+    assert_eq!(SystemDescriptor::RESERVED_MASK, [0, 0xFFFF_FFFF_0000_0000]);
+    assert!(!descriptor.is_valid());
+    assert_eq!(descriptor.sanitize().0, KERNEL_TSS);
+}
+
+#[test]
+pub fn test_build_kernel_tss() {
+    let mut descriptor = SystemDescriptor([0; 2]);
+    descriptor
+        .set_limit_0_15(0x67)
+        .set_base_0_23((KERNEL_TSS_BASE & 0xFF_FFFF) as u32)
+        .set_base_24_63(KERNEL_TSS_BASE >> 24)
+        .set_system_type(0b1001)
+        .set_present(true);
+
+    assert_eq!(descriptor.0, KERNEL_TSS);
+}
+
+#[test]
+pub fn test_debug_kernel_tss() {
+    let descriptor = SystemDescriptor(KERNEL_TSS);
+    let output = format!("{:?}", descriptor);
+
+    assert!(output.starts_with("SystemDescriptor { raw: 0x00000000ffff80001200893456780067, limit_0_15: 103,"));
+    assert!(output.ends_with("base_24_63: 1099503239186 }"));
+}
+
+#[test]
+pub fn test_bytes_kernel_tss() {
+    let mut bytes = [0; 16];
+    bytes[..8].copy_from_slice(&KERNEL_TSS[0].to_le_bytes());
+    bytes[8..].copy_from_slice(&KERNEL_TSS[1].to_le_bytes());
+
+    let descriptor = SystemDescriptor::from_le_bytes(bytes);
+    assert_eq!(descriptor.0, KERNEL_TSS);
+    assert_eq!(descriptor.to_le_bytes(), bytes);
+
+    bytes.reverse();
+    assert_eq!(SystemDescriptor::from_be_bytes(bytes).0, KERNEL_TSS);
+    assert_eq!(descriptor.to_be_bytes(), bytes);
+}
+
+#[test]
+pub fn test_wide_fields() {
+    let mut wide = Wide([0; 8]);
+    wide.set_low(0xAB)
+        .set_straddle(0x0123_4567_89AB_CDEF)
+        .set_signed(-2)
+        .set_high(u128::MAX);
+
+    assert_eq!(wide.0, [0xAB, 0x89AB_CDEF, 0x0123_4567, 0x8000_0000, 0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF]);
+    assert_eq!(wide.low(), 0xAB);
+    assert_eq!(wide.straddle(), 0x0123_4567_89AB_CDEF);
+    assert_eq!(wide.signed(), -2);
+    assert_eq!(wide.high(), u128::MAX);
+
+    wide.set_high(0);
+    assert_eq!(wide.signed(), 0b10);
+
+    wide.set_signed(-2048);
+    assert_eq!(wide.0[3], 0);
+    assert_eq!(wide.0[4], 0x200);
+    assert_eq!(wide.signed(), -2048);
+}
+
+#[test]
+pub fn test_wide_field_consts() {
+    assert_eq!(Wide::STRADDLE_MASK, [0, 0xFFFF_FFFF, 0xFFFF_FFFF, 0, 0, 0, 0, 0]);
+    assert_eq!(Wide::STRADDLE_SHIFT, 32);
+    assert_eq!(Wide::STRADDLE_WIDTH, 64);
+
+    assert_eq!(Wide::SIGNED_MASK, [0, 0, 0, 0xC000_0000, 0x3FF, 0, 0, 0]);
+    assert_eq!(Wide::HIGH_WIDTH, 128);
+}
@@ -0,0 +1,207 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::{bitfield, BitfieldError};
+use std::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering};
+use std::thread;
+
+const THREADS: usize = 8;
+const ITERATIONS: usize = 10_000;
+
+/// Getter and setter of a single field, used for letting every thread pick its own field.
+type Accessors<T, V> = (fn(&T, Ordering) -> V, fn(&T, V, Ordering) -> &T);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum State {
+    Idle,
+    Busy,
+}
+
+impl From<bool> for State {
+    fn from(value: bool) -> Self {
+        if value {
+            State::Busy
+        } else {
+            State::Idle
+        }
+    }
+}
+
+impl From<State> for bool {
+    fn from(value: State) -> Self {
+        value == State::Busy
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Priority {
+    Low,
+    High,
+}
+
+impl TryFrom<u8> for Priority {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Priority::Low),
+            2 => Ok(Priority::High),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<Priority> for u8 {
+    type Error = u8;
+
+    fn try_from(value: Priority) -> Result<Self, Self::Error> {
+        Ok(value as u8 + 1)
+    }
+}
+
+bitfield! {
+    pub struct Flags(AtomicU8);
+    bool, ready, set_ready: 0;
+    bool, from into State, state, set_state: 1;
+    u8, try_from try_into Priority, priority, set_priority: 3, 2;
+    u8, level, set_level: 7, 4;
+}
+
+bitfield! {
+    pub struct Done(AtomicU8);
+    bool, done0, set_done0: 0;
+    bool, done1, set_done1: 1;
+    bool, done2, set_done2: 2;
+    bool, done3, set_done3: 3;
+    bool, done4, set_done4: 4;
+    bool, done5, set_done5: 5;
+    bool, done6, set_done6: 6;
+    bool, done7, set_done7: 7;
+}
+
+bitfield! {
+    pub struct Lanes(AtomicU64);
+    u8, lane0, set_lane0: 7, 0;
+    u8, lane1, set_lane1: 15, 8;
+    u8, lane2, set_lane2: 23, 16;
+    u8, lane3, set_lane3: 31, 24;
+    u8, lane4, set_lane4: 39, 32;
+    u8, lane5, set_lane5: 47, 40;
+    u8, lane6, set_lane6: 55, 48;
+    u8, lane7, set_lane7: 63, 56;
+}
+
+bitfield! {
+    pub struct Bits(AtomicU32);
+    pub u32, raw, _: 31, 0;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    pub struct Header(AtomicU16);
+    u8, version, set_version: 0, 3;
+    bool, urgent, set_urgent: 15;
+}
+
+#[test]
+pub fn test_atomic_accessors() {
+    let flags = Flags(AtomicU8::new(0));
+    flags.set_ready(true, Ordering::Relaxed).set_level(0xA, Ordering::Relaxed);
+    flags.set_state(State::Busy, Ordering::Release);
+
+    assert!(flags.ready(Ordering::Relaxed));
+    assert_eq!(flags.state(Ordering::Acquire), State::Busy);
+    assert_eq!(flags.level(Ordering::SeqCst), 0xA);
+    assert_eq!(flags.0.load(Ordering::Relaxed), 0xA3);
+
+    flags.set_ready(false, Ordering::AcqRel).set_state(State::Idle, Ordering::SeqCst);
+    assert_eq!(flags.0.load(Ordering::Relaxed), 0xA0);
+    assert_eq!(Flags::LEVEL_MASK, 0xF0);
+
+    assert_eq!(flags.priority(Ordering::Relaxed), Err(0));
+    flags.set_priority(Priority::High, Ordering::Relaxed).unwrap();
+    assert_eq!(flags.priority(Ordering::Relaxed), Ok(Priority::High));
+    assert_eq!(flags.0.load(Ordering::Relaxed), 0xA8);
+}
+
+#[test]
+pub fn test_atomic_try_setters() {
+    let flags = Flags(AtomicU8::new(0));
+    assert_eq!(flags.try_set_level(0x10, Ordering::Relaxed).err(), Some(BitfieldError::ValueTruncated));
+    assert_eq!(flags.0.load(Ordering::Relaxed), 0);
+    assert!(flags.try_set_level(0xF, Ordering::Relaxed).is_ok());
+    assert!(flags.try_set_ready(true, Ordering::Relaxed).is_ok());
+    assert_eq!(flags.0.load(Ordering::Relaxed), 0xF1);
+}
+
+#[test]
+pub fn test_atomic_msb0() {
+    let header = Header(AtomicU16::new(0));
+    header.set_version(0x4, Ordering::Relaxed).set_urgent(true, Ordering::Relaxed);
+    assert_eq!(header.0.load(Ordering::Relaxed), 0x4001);
+    assert_eq!(header.version(Ordering::Relaxed), 0x4);
+    assert_eq!(Bits(AtomicU32::new(0xDEAD_BEEF)).raw(Ordering::Relaxed), 0xDEAD_BEEF);
+}
+
+#[test]
+pub fn test_atomic_concurrent_bits() {
+    let toggled = Done(AtomicU8::new(0));
+    let set = Done(AtomicU8::new(0));
+
+    // every thread toggles its own bit using fetch_or / fetch_and without clobbering the others
+    thread::scope(|scope| {
+        for index in 0..THREADS {
+            let (toggled, set) = (&toggled, &set);
+            scope.spawn(move || {
+                let (get, put): Accessors<Done, bool> = match index {
+                    0 => (Done::done0, Done::set_done0),
+                    1 => (Done::done1, Done::set_done1),
+                    2 => (Done::done2, Done::set_done2),
+                    3 => (Done::done3, Done::set_done3),
+                    4 => (Done::done4, Done::set_done4),
+                    5 => (Done::done5, Done::set_done5),
+                    6 => (Done::done6, Done::set_done6),
+                    _ => (Done::done7, Done::set_done7),
+                };
+                for iteration in 0..ITERATIONS {
+                    put(toggled, !get(toggled, Ordering::Relaxed), Ordering::Relaxed);
+                    put(set, iteration % 2 == 0, Ordering::Relaxed);
+                }
+                put(set, true, Ordering::Relaxed);
+            });
+        }
+    });
+
+    assert_eq!(toggled.0.load(Ordering::Relaxed), 0x00);
+    assert_eq!(set.0.load(Ordering::Relaxed), 0xFF);
+}
+
+#[test]
+pub fn test_atomic_concurrent_ranges() {
+    let lanes = Lanes(AtomicU64::new(0));
+
+    // every thread counts within its own lane using read-modify-write cycles of the whole word
+    thread::scope(|scope| {
+        for index in 0..THREADS {
+            let lanes = &lanes;
+            scope.spawn(move || {
+                let (get, set): Accessors<Lanes, u8> = match index {
+                    0 => (Lanes::lane0, Lanes::set_lane0),
+                    1 => (Lanes::lane1, Lanes::set_lane1),
+                    2 => (Lanes::lane2, Lanes::set_lane2),
+                    3 => (Lanes::lane3, Lanes::set_lane3),
+                    4 => (Lanes::lane4, Lanes::set_lane4),
+                    5 => (Lanes::lane5, Lanes::set_lane5),
+                    6 => (Lanes::lane6, Lanes::set_lane6),
+                    _ => (Lanes::lane7, Lanes::set_lane7),
+                };
+                for _ in 0..ITERATIONS {
+                    set(lanes, get(lanes, Ordering::Relaxed).wrapping_add(1), Ordering::Relaxed);
+                }
+            });
+        }
+    });
+
+    let expected = (ITERATIONS % 256) as u64;
+    assert_eq!(lanes.0.load(Ordering::Relaxed), expected * 0x0101_0101_0101_0101);
+}
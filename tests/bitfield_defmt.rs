@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct Permissions(u8);
+    impl Format;
+    bool, read, set_read: 0;
+    bool, write, set_write: 1;
+}
+
+bitfield! {
+    pub struct Control(u16);
+    impl Format;
+    u8, level, set_level: 7, 0;
+    u8, nested Permissions, permissions, set_permissions: 9, 8;
+    bool, _, set_reset: 15;
+}
+
+bitfield! {
+    pub struct Lanes(u16);
+    impl Format;
+    u8, prio, set_prio: [3] 3, 0;
+    bool, _, set_flag: [2] 12;
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+#[test]
+pub fn test_defmt() {
+    defmt::export::fetch_bytes();
+    defmt::println!("{}", Control(0x022A));
+    let bytes = defmt::export::fetch_bytes();
+
+    // names are transmitted as `{=str}` arguments, while the layout is interned on the host
+    assert!(contains(&bytes, b"Control"));
+    assert!(contains(&bytes, b"level"));
+    assert!(contains(&bytes, b"permissions"));
+    assert!(contains(&bytes, b"Permissions"));
+    assert!(contains(&bytes, b"write"));
+    assert!(!contains(&bytes, b"reset"));
+}
+
+#[test]
+pub fn test_defmt_arrays() {
+    defmt::export::fetch_bytes();
+    defmt::println!("{}", Lanes(0x1321));
+    let bytes = defmt::export::fetch_bytes();
+
+    assert!(contains(&bytes, b"prio"));
+    assert!(!contains(&bytes, b"flag"));
+}
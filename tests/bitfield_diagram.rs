@@ -0,0 +1,102 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    pub struct Status(u8);
+    impl Diagram;
+    bool, ready, set_ready: 0;
+    u8, count, set_count: 3, 1;
+    bool, enable, _: 7;
+}
+
+bitfield! {
+    pub struct Segment(u64);
+    impl Diagram;
+    u32, limit, set_limit: 15, 0;
+    u32, base_low, set_base_low: 39, 16;
+    u8, access, set_access: 47, 40;
+    u8, base_high, set_base_high: 63, 56;
+}
+
+bitfield! {
+    pub struct Priorities(u16);
+    impl Diagram;
+    u8, prio, set_prio: [3] 3, 0 stride 5;
+    bool, on, set_on: [2] 14;
+}
+
+bitfield! {
+    pub struct Unicode(u8);
+    impl Diagram;
+    bool, größe, set_größe: 0;
+    u8, länge, set_länge: 7, 4;
+}
+
+#[test]
+pub fn test_diagram() {
+    let diagram = Status(0x8B).diagram().to_string();
+    assert_eq!(
+        diagram,
+        "  7   6   5   4   3   2   1   0 \n\
+         +---+-----------+-----------+---+\n\
+         |ena|           |   count   |rea|\n\
+         | 1 |    0x0    |    0x5    | 1 |\n\
+         +---+-----------+-----------+---+"
+    );
+}
+
+#[test]
+pub fn test_diagram_rows() {
+    let diagram = Segment(0x00CF_9300_0000_FFFF).diagram().to_string();
+    let lines: Vec<&str> = diagram.lines().collect();
+
+    // fields are split across rows of 32 bits, starting with the most significant row
+    assert_eq!(lines.len(), 11);
+    assert!(lines[0].starts_with(" 63  62  61"));
+    assert_eq!(
+        lines[2],
+        "|           base_high           |                               |            access             \
+         |           base_low            |"
+    );
+    assert_eq!(
+        lines[3],
+        "|              0x0              |             0xcf              |             0x93              \
+         |              0x0              |"
+    );
+    assert_eq!(lines[5], "");
+    assert!(lines[6].ends_with("  1   0 "));
+    assert!(lines[8].ends_with("|                             limit                             |"));
+    assert!(lines[9].ends_with("|                            0xffff                             |"));
+    assert!(lines.iter().all(|line| line.len() <= 129));
+}
+
+#[test]
+pub fn test_diagram_array() {
+    let diagram = Priorities(0x4A23).diagram().to_string();
+    let lines: Vec<&str> = diagram.lines().collect();
+
+    // indices of array elements are only shown if they fit along with the name
+    assert_eq!(
+        lines[2],
+        "|on |on |    prio[2]    |   |    prio[1]    |   |    prio[0]    |"
+    );
+    assert_eq!(
+        lines[3],
+        "| 0 | 1 |      0x2      | 1 |      0x1      | 0 |      0x3      |"
+    );
+}
+
+#[test]
+pub fn test_diagram_unicode() {
+    let diagram = Unicode(0xA1).diagram().to_string();
+    assert_eq!(
+        diagram,
+        "  7   6   5   4   3   2   1   0 \n\
+         +---------------+-----------+---+\n\
+         |     länge     |           |grö|\n\
+         |      0xa      |    0x0    | 1 |\n\
+         +---------------+-----------+---+"
+    );
+}
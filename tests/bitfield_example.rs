@@ -1,6 +1,6 @@
-#![feature(const_convert)] // optional, when using from/into conversion
-#![feature(const_mut_refs)] // always required
-#![feature(const_trait_impl)] // always required
+#![cfg_attr(not(feature = "stable"), feature(const_convert))] // optional, when using from/into conversion
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))] // always required
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))] // always required
 
 use const_bitfield::bitfield;
 
@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::{bitfield, BitfieldError, FieldAccess, FieldArray, FieldInfo};
+
+bitfield! {
+    pub struct Status(u16);
+    bool, ready, set_ready: 0;
+    u8, count, _: 7, 1;
+    reserved 0: 8;
+    i8, _, set_offset: 12, 9;
+    bool, flag, set_flag: [2] 13 stride 2;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    pub struct Header([u32; 2]);
+    u8, version, set_version: 0, 3 = 4;
+    u8, lane, set_lane: [2] 4, 7;
+    overlap u64, low, set_low: 32, 63;
+    overlap u32, word, _: 32, 63;
+}
+
+bitfield! {
+    pub struct Shared(AtomicU8);
+    bool, busy, set_busy: 7;
+}
+
+#[test]
+pub fn test_fields_metadata() {
+    assert_eq!(
+        Status::FIELDS,
+        &[
+            FieldInfo { name: "ready", msb: 0, lsb: 0, access: FieldAccess::ReadWrite, raw_type: "bool", array: None },
+            FieldInfo { name: "count", msb: 7, lsb: 1, access: FieldAccess::Read, raw_type: "u8", array: None },
+            FieldInfo { name: "set_offset", msb: 12, lsb: 9, access: FieldAccess::Write, raw_type: "i8", array: None },
+            FieldInfo {
+                name: "flag",
+                msb: 13,
+                lsb: 13,
+                access: FieldAccess::ReadWrite,
+                raw_type: "bool",
+                array: Some(FieldArray { count: 2, step: 2 }),
+            },
+        ]
+    );
+    assert_eq!(
+        Header::FIELDS,
+        &[
+            FieldInfo { name: "version", msb: 63, lsb: 60, access: FieldAccess::ReadWrite, raw_type: "u8", array: None },
+            FieldInfo {
+                name: "lane",
+                msb: 59,
+                lsb: 56,
+                access: FieldAccess::ReadWrite,
+                raw_type: "u8",
+                array: Some(FieldArray { count: 2, step: -4 }),
+            },
+            FieldInfo { name: "low", msb: 31, lsb: 0, access: FieldAccess::ReadWrite, raw_type: "u64", array: None },
+            FieldInfo { name: "word", msb: 31, lsb: 0, access: FieldAccess::Read, raw_type: "u32", array: None },
+        ]
+    );
+    assert_eq!(Header::FIELDS[1].element(1), Some((55, 52)));
+    assert_eq!(Header::FIELDS[1].element(2), None);
+    assert_eq!(Shared::FIELDS[0].msb, 7);
+    assert!(FieldAccess::ReadWrite.is_readable() && FieldAccess::ReadWrite.is_writable());
+}
+
+#[test]
+pub fn test_fields_by_name() {
+    let mut status = Status(0x00A5);
+    assert_eq!(status.get_by_name("ready"), Some(1));
+    assert_eq!(status.get_by_name("count"), Some(0x52));
+    assert_eq!(status.get_by_name("set_offset"), None);
+    assert_eq!(status.get_by_name("flag"), None);
+    assert_eq!(status.get_by_name("missing"), None);
+
+    status.set_by_name("set_offset", 0xF).unwrap().set_by_name("ready", 0).unwrap();
+    assert_eq!(status.0, 0x1EA4);
+    assert_eq!(status.set_by_name("set_offset", 0x10).err(), Some(BitfieldError::ValueTruncated));
+    assert_eq!(status.set_by_name("count", 1).err(), Some(BitfieldError::UnknownField));
+    assert_eq!(status.set_by_name("missing", 1).err(), Some(BitfieldError::UnknownField));
+    assert_eq!(status.0, 0x1EA4);
+
+    status.set_by_name("flag[1]", 1).unwrap();
+    assert_eq!(status.0, 0x9EA4);
+    assert_eq!(status.get_by_name("flag[0]"), Some(0));
+    assert_eq!(status.get_by_name("flag[1]"), Some(1));
+    assert_eq!(status.get_by_name("flag[2]"), None);
+    assert_eq!(status.get_by_name("ready[0]"), None);
+    assert_eq!(status.set_by_name("flag[x]", 1).err(), Some(BitfieldError::UnknownField));
+
+    let mut header = Header::new();
+    header.set_by_name("low", 0xDEAD_BEEF).unwrap();
+    assert_eq!(header.get_by_name("word"), Some(0xDEAD_BEEF));
+    assert_eq!(header.get_by_name("version"), Some(4));
+    header.set_by_name("lane[1]", 0xA).unwrap();
+    assert_eq!(header.lane(1), 0xA);
+    assert_eq!(header.get_by_name("lane[1]"), Some(0xA));
+}
@@ -1,9 +1,10 @@
-#![feature(const_convert)]
-#![feature(const_option)]
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_option))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
 use const_bitfield::bitfield;
+#[cfg(not(feature = "stable"))]
 use const_enum::ConstEnum;
 
 const KERNEL_CODE64: u64 = 0x00AF9B000000FFFF;
@@ -17,25 +18,37 @@ pub enum DescriptorType {
     UserSegment = 1,
 }
 
-impl const From<bool> for DescriptorType {
+// const trait implementation on Rust nightly, regular trait implementation with `stable` feature
+#[cfg(not(feature = "stable"))]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl const $($impl)* };
+}
+
+#[cfg(feature = "stable")]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl $($impl)* };
+}
+
+impl_convert! {From<bool> for DescriptorType {
     fn from(value: bool) -> Self {
         match value {
             false => DescriptorType::SystemSegment,
             true => DescriptorType::UserSegment,
         }
     }
-}
+}}
 
-impl const From<DescriptorType> for bool {
+impl_convert! {From<DescriptorType> for bool {
     fn from(value: DescriptorType) -> Self {
         match value {
             DescriptorType::SystemSegment => false,
             DescriptorType::UserSegment => true,
         }
     }
-}
+}}
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, ConstEnum)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "stable"), derive(ConstEnum))]
 #[repr(u8)]
 pub enum SegmentType {
     DataReadOnly = 0b000,
@@ -48,9 +61,34 @@ pub enum SegmentType {
     CodeExecReadConforming = 0b111,
 }
 
+#[cfg(feature = "stable")]
+impl From<SegmentType> for u8 {
+    fn from(value: SegmentType) -> Self {
+        value as Self
+    }
+}
+
+#[cfg(feature = "stable")]
+impl From<u8> for SegmentType {
+    fn from(value: u8) -> Self {
+        match value {
+            0b000 => SegmentType::DataReadOnly,
+            0b001 => SegmentType::DataReadWrite,
+            0b010 => SegmentType::DataReadOnlyDown,
+            0b011 => SegmentType::DataReadWriteDown,
+            0b100 => SegmentType::CodeExecOnly,
+            0b101 => SegmentType::CodeExecRead,
+            0b110 => SegmentType::CodeExecOnlyConforming,
+            0b111 => SegmentType::CodeExecReadConforming,
+            _ => panic!("invalid value provided"),
+        }
+    }
+}
+
 bitfield! {
     #[derive(Copy, Clone)]
     pub struct Descriptor(u64);
+    impl Debug;
     u32, limit_0_15, set_limit_0_15: 15, 0;
     u32, base_0_15, set_base_0_15: 31, 16;
     u8, base_16_23, set_base_16_23: 39, 32;
@@ -58,8 +96,9 @@ bitfield! {
     u8, from into SegmentType, segment_type, set_segment_type: 43, 41;
     bool, from into DescriptorType, descriptor_type, set_descriptor_type: 44;
     u8, privilege_level, set_privilege_level: 46, 45;
-    bool, present, set_present: 47;
+    bool, present, set_present: 47 = true;
     u8, limit_16_19, set_limit_16_19: 51, 48;
+    reserved 0: 52;
     bool, long_mode, set_long_mode: 53;
     bool, size_flag, set_size_flag: 54;
     bool, granularity, set_granularity: 55;
@@ -114,6 +153,60 @@ pub fn test_build_kernel_code64() {
     assert_eq!(descriptor.0, KERNEL_CODE64);
 }
 
+#[test]
+pub fn test_with_kernel_code64() {
+    let descriptor = Descriptor(0)
+        .with_limit_0_15(0xFFFF)
+        .with_limit_16_19(0xF)
+        .with_accessed(true)
+        .with_segment_type(SegmentType::CodeExecRead)
+        .with_descriptor_type(DescriptorType::UserSegment)
+        .with_present(true)
+        .with_long_mode(true)
+        .with_granularity(true);
+    assert_eq!(descriptor.0, KERNEL_CODE64);
+    assert_eq!(Descriptor(KERNEL_CODE64).with_long_mode(false).with_size_flag(true).0, KERNEL_CODE32);
+}
+
+#[test]
+pub fn test_field_consts() {
+    assert_eq!(Descriptor::PRESENT_MASK, 1 << 47);
+    assert_eq!(Descriptor::PRESENT_SHIFT, 47);
+    assert_eq!(Descriptor::PRESENT_WIDTH, 1);
+
+    assert_eq!(Descriptor::SEGMENT_TYPE_MASK, 0b111 << 41);
+    assert_eq!(Descriptor::SEGMENT_TYPE_SHIFT, 41);
+    assert_eq!(Descriptor::SEGMENT_TYPE_WIDTH, 3);
+
+    assert_eq!(KERNEL_CODE64 & Descriptor::LIMIT_0_15_MASK, 0xFFFF);
+    assert_eq!((KERNEL_CODE64 & Descriptor::LIMIT_16_19_MASK) >> Descriptor::LIMIT_16_19_SHIFT, 0xF);
+}
+
+#[test]
+pub fn test_reserved() {
+    assert_eq!(Descriptor::RESERVED_MASK, 1 << 52);
+    assert!(Descriptor(KERNEL_CODE64).is_valid());
+    assert!(!Descriptor(KERNEL_CODE64 | 1 << 52).is_valid());
+    assert_eq!(Descriptor(KERNEL_CODE64 | 1 << 52).sanitize().0, KERNEL_CODE64);
+}
+
+#[test]
+pub fn test_defaults() {
+    const DEFAULT: Descriptor = Descriptor::new();
+    assert_eq!(DEFAULT.0, 1 << 47);
+    assert!(DEFAULT.present());
+
+    let descriptor = Descriptor::default()
+        .with_segment_type(SegmentType::DataReadWrite)
+        .with_descriptor_type(DescriptorType::UserSegment)
+        .with_limit_0_15(0xFFFF)
+        .with_limit_16_19(0xF)
+        .with_accessed(true)
+        .with_size_flag(true)
+        .with_granularity(true);
+    assert_eq!(descriptor.0, KERNEL_DATA);
+}
+
 #[test]
 pub fn test_parse_kernel_code32() {
     let descriptor = Descriptor(KERNEL_CODE32);
@@ -209,3 +302,15 @@ pub fn test_build_kernel_data() {
 
     assert_eq!(descriptor.0, KERNEL_DATA);
 }
+
+#[test]
+pub fn test_debug_kernel_code64() {
+    let descriptor = Descriptor(KERNEL_CODE64);
+
+    assert_eq!(
+        format!("{:?}", descriptor),
+        "Descriptor { raw: 0x00af9b000000ffff, limit_0_15: 65535, base_0_15: 0, base_16_23: 0, \
+         accessed: true, segment_type: CodeExecRead, descriptor_type: UserSegment, privilege_level: 0, \
+         present: true, limit_16_19: 15, long_mode: true, size_flag: false, granularity: true, base_24_31: 0 }"
+    );
+}
@@ -0,0 +1,69 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_ops))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Flags(u16);
+    impl BitOps;
+    bool, read, set_read: 0;
+    bool, write, set_write: 1;
+    bool, execute, set_execute: 2;
+    u8, owner, set_owner: 15, 8;
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Wide([u32; 3]);
+    impl BitOps;
+    u64, low, set_low: 63, 0;
+    u32, high, set_high: 95, 64;
+}
+
+const READ: Flags = Flags(0).with_read(true);
+const WRITE: Flags = Flags(0).with_write(true);
+
+#[test]
+pub fn test_bitops() {
+    let flags = READ | WRITE;
+    assert_eq!(flags, Flags(0b011));
+    assert_eq!(flags & WRITE, WRITE);
+    assert_eq!(flags ^ READ, WRITE);
+    assert_eq!(!flags, Flags(0xFFFC));
+    assert_eq!(Flags(0xABCD) & Flags::OWNER_MASK, Flags(0xAB00));
+
+    let mut flags = Flags(0x1234);
+    flags |= READ;
+    assert_eq!(flags, Flags(0x1235));
+    flags &= Flags(0xFF00) | WRITE;
+    assert_eq!(flags, Flags(0x1200));
+    flags ^= Flags(0x1201);
+    assert_eq!(flags, Flags(0x0001));
+}
+
+#[test]
+pub fn test_bitops_array() {
+    let value = Wide([0, 0, 0]).with_low(0x0123_4567_89AB_CDEF).with_high(0xFFFF_0000);
+    assert_eq!((value & Wide([u32::MAX, 0, u32::MAX])).0, [0x89AB_CDEF, 0, 0xFFFF_0000]);
+    assert_eq!((value | Wide([0, 0, 0xFFFF])).high(), u32::MAX);
+    assert_eq!((!value).low(), !0x0123_4567_89AB_CDEF);
+    assert_eq!((value ^ value).0, [0; 3]);
+    assert_eq!((value & [0, u32::MAX, 0]).low(), 0x0123_4567_0000_0000);
+}
+
+// const trait implementations are only available on Rust nightly
+#[cfg(not(feature = "stable"))]
+#[test]
+pub fn test_bitops_const() {
+    const FLAGS: Flags = {
+        let mut flags = READ | WRITE;
+        flags &= !READ;
+        flags ^ Flags(0).with_owner(0x42)
+    };
+    assert_eq!(FLAGS, Flags(0x4202));
+
+    const WIDE: Wide = (!Wide([0, 0, 0]) ^ Wide([0xF, 0, 0])) & Wide([0xFF, 0x2, 0x3]);
+    assert_eq!(WIDE.0, [0xF0, 0x2, 0x3]);
+}
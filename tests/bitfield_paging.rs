@@ -0,0 +1,109 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+const KERNEL_PAGE: u64 = 0x8000_0000_0010_2003;
+const USER_PAGE: u64 = 0x0000_0000_0020_0007;
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Permissions(u8);
+    bool, writable, set_writable: 0;
+    bool, user, set_user: 1;
+    bool, executable, set_executable: 2;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct PageTableEntry(u64);
+    impl Debug;
+    bool, present, set_present: 0;
+    u8, nested Permissions, permissions, set_permissions: 3, 1;
+    u64, frame, set_frame: 51, 12;
+    bool, no_execute, set_no_execute: 63;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct AccessGroups(u16);
+    u8, nested Permissions, group, set_group: [3] 2, 0 stride 4;
+    u8, nested Permissions, fallback, set_fallback: 14, 12 = Permissions(0b100);
+}
+
+const KERNEL: PageTableEntry = PageTableEntry(0)
+    .with_present(true)
+    .with_permissions(Permissions(0).with_writable(true))
+    .with_frame(0x102)
+    .with_no_execute(true);
+
+#[test]
+pub fn test_parse_nested() {
+    let entry = PageTableEntry(USER_PAGE);
+
+    assert!(entry.present());
+    assert_eq!(entry.permissions(), Permissions(0b011));
+    assert!(entry.permissions().writable());
+    assert!(entry.permissions().user());
+    assert!(!entry.permissions().executable());
+    assert_eq!(entry.frame(), 0x200);
+}
+
+#[test]
+pub fn test_build_nested() {
+    let mut entry = PageTableEntry(0);
+    entry
+        .set_present(true)
+        .set_permissions(Permissions(0).with_writable(true).with_user(true))
+        .set_frame(0x200);
+
+    assert_eq!(entry.0, USER_PAGE);
+    assert_eq!(KERNEL.0, KERNEL_PAGE);
+}
+
+#[test]
+pub fn test_modify_nested() {
+    let mut entry = PageTableEntry(KERNEL_PAGE);
+    entry.permissions_mut().set_user(true).set_writable(false);
+    assert_eq!(entry.0, KERNEL_PAGE ^ 0b110);
+
+    {
+        let mut permissions = entry.permissions_mut();
+        permissions.set_executable(true);
+        assert!(permissions.executable());
+    }
+    assert!(entry.permissions().executable());
+    assert_eq!(entry.frame(), 0x102);
+}
+
+#[test]
+pub fn test_modify_nested_excess_bits() {
+    let mut entry = PageTableEntry(0);
+    entry.permissions_mut().0 = 0xFF;
+    assert_eq!(entry.0, 0b1110);
+    assert_eq!(entry.permissions(), Permissions(0b111));
+}
+
+#[test]
+pub fn test_nested_groups() {
+    let mut groups = AccessGroups::new();
+    assert_eq!(groups.fallback(), Permissions(0b100));
+
+    groups.set_group(2, Permissions(0).with_user(true));
+    groups.fallback_mut().set_writable(true);
+    assert_eq!(groups.0, 0x5200);
+    assert!(groups.group(2).user());
+    assert_eq!(groups.group(0), Permissions(0));
+
+    groups.reset_fallback();
+    assert_eq!(groups.0, 0x4200);
+}
+
+#[test]
+pub fn test_debug_nested() {
+    assert_eq!(
+        format!("{:?}", PageTableEntry(USER_PAGE)),
+        "PageTableEntry { raw: 0x0000000000200007, present: true, permissions: Permissions(3), frame: 512, \
+         no_execute: false }"
+    );
+}
@@ -0,0 +1,81 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::{bitfield, Register};
+
+const RESET: u32 = 0x0000_0A00;
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct Control(u32);
+    bool, enable, set_enable: 0;
+    bool, interrupt, set_interrupt: 1;
+    u8, mode, set_mode: 5, 4;
+    u8, divider, set_divider: 15, 8;
+    u16, status, _: 31, 16;
+}
+
+/// Heap-allocated backing word standing in for a memory-mapped register.
+struct Backing(*mut u32);
+
+impl Backing {
+    fn new(value: u32) -> Self {
+        Self(Box::into_raw(Box::new(value)))
+    }
+
+    fn register(&self) -> Register<Control> {
+        unsafe { Register::new(self.0 as *mut Control) }
+    }
+
+    fn get(&self) -> u32 {
+        unsafe { self.0.read_volatile() }
+    }
+
+    fn set(&self, value: u32) {
+        unsafe { self.0.write_volatile(value) }
+    }
+}
+
+impl Drop for Backing {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+
+#[test]
+pub fn test_register_read() {
+    let backing = Backing::new(RESET);
+    let register = backing.register();
+    assert_eq!(register.read().divider(), 0xA);
+    assert!(!register.read().enable());
+
+    // every read observes the current value of the backing word
+    backing.set(0xBEEF_0A01);
+    assert!(register.read().enable());
+    assert_eq!(register.read().status(), 0xBEEF);
+    assert_eq!(register.as_ptr() as *mut u32, backing.0);
+}
+
+#[test]
+pub fn test_register_write() {
+    let backing = Backing::new(RESET);
+    let register = backing.register();
+    register.write(Control(0).with_enable(true).with_mode(0b10));
+    assert_eq!(backing.get(), 0x0000_0021);
+}
+
+#[test]
+pub fn test_register_modify() {
+    let backing = Backing::new(RESET);
+    let register = backing.register();
+    register.modify(|control| {
+        control.set_interrupt(true).set_divider(0x42);
+    });
+    assert_eq!(backing.get(), 0x0000_4202);
+
+    register.modify(|control| {
+        control.set_interrupt(false);
+    });
+    assert_eq!(backing.get(), 0x0000_4200);
+    assert_eq!(register.read().divider(), 0x42);
+}
@@ -0,0 +1,168 @@
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum State {
+    Idle,
+    Busy,
+}
+
+impl From<bool> for State {
+    fn from(value: bool) -> Self {
+        if value {
+            State::Busy
+        } else {
+            State::Idle
+        }
+    }
+}
+
+impl From<State> for bool {
+    fn from(value: State) -> Self {
+        value == State::Busy
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Mode {
+    User,
+    Supervisor,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b10 => Ok(Mode::User),
+            0b11 => Ok(Mode::Supervisor),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<Mode> for u8 {
+    type Error = u8;
+
+    fn try_from(value: Mode) -> Result<Self, Self::Error> {
+        match value {
+            Mode::User => Ok(0b10),
+            Mode::Supervisor => Ok(0b11),
+        }
+    }
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Permissions(u8);
+    impl Serde;
+    bool, read, set_read: 0;
+    bool, write, set_write: 1;
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Control(u16);
+    impl Serde;
+    u8, try_from try_into Mode, mode, set_mode: 1, 0;
+    bool, from into State, state, set_state: 2;
+    u8, nested Permissions, permissions, set_permissions: 4, 3;
+    u8, level, set_level: 11, 8;
+    u8, version, _: 15, 12;
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Raw(u8);
+    impl SerdeRaw;
+    u8, low, set_low: 3, 0;
+    u8, high, set_high: 7, 4;
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Lanes(u16);
+    impl Serde;
+    u8, prio, set_prio: [3] 3, 0;
+    bool, from into State, state, set_state: [2] 12;
+    u8, try_from try_into Mode, mode, _: [1] 15, 14;
+}
+
+#[test]
+pub fn test_serde_fields() {
+    let mut control = Control(0x1000);
+    control.set_mode(Mode::Supervisor).unwrap();
+    control.set_state(State::Busy).set_level(0xA);
+    control.permissions_mut().set_read(true);
+
+    let json = serde_json::to_string(&control).unwrap();
+    assert_eq!(
+        json,
+        r#"{"mode":"Supervisor","state":"Busy","permissions":{"read":true,"write":false},"level":10,"version":1}"#
+    );
+
+    // fields without setter are accepted but ignored
+    let parsed: Control = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, Control(control.0 & 0x0FFF));
+
+    let parsed: Control = serde_json::from_str(r#"{"level":3}"#).unwrap();
+    assert_eq!(parsed, Control(0x0300));
+}
+
+#[test]
+pub fn test_serde_fields_invalid() {
+    let error = serde_json::to_string(&Control(0)).unwrap_err();
+    assert_eq!(error.to_string(), "field `mode` holds an invalid value");
+
+    let error = serde_json::from_str::<Control>(r#"{"level":16}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid value for field `level`: value truncated"));
+
+    let error = serde_json::from_str::<Control>(r#"{"flags":1}"#).unwrap_err();
+    assert!(error.to_string().starts_with("unknown field `flags`"));
+
+    let error = serde_json::from_str::<Control>(r#"{"mode":"Root"}"#).unwrap_err();
+    assert!(error.to_string().starts_with("unknown variant `Root`"));
+
+    let error = serde_json::from_str::<Control>(r#"[1, 2]"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid type: sequence, expected a map of the fields of `Control`"));
+}
+
+#[test]
+pub fn test_serde_arrays() {
+    let mut lanes = Lanes(0xC000);
+    lanes.set_prio(0, 1).set_prio(1, 2).set_prio(2, 3).set_state(1, State::Busy);
+
+    let json = serde_json::to_string(&lanes).unwrap();
+    assert_eq!(json, r#"{"prio":[1,2,3],"state":["Idle","Busy"],"mode":["Supervisor"]}"#);
+
+    // elements of field arrays without setter are accepted but ignored
+    let parsed: Lanes = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, Lanes(lanes.0 & 0x3FFF));
+}
+
+#[test]
+pub fn test_serde_arrays_invalid() {
+    let error = serde_json::to_string(&Lanes(0)).unwrap_err();
+    assert_eq!(error.to_string(), "field `mode` holds an invalid value");
+
+    let error = serde_json::from_str::<Lanes>(r#"{"prio":[1,2]}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid length 2, expected a sequence of 3 elements"));
+
+    let error = serde_json::from_str::<Lanes>(r#"{"prio":[1,2,3,4]}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid length 4, expected a sequence of 3 elements"));
+
+    let error = serde_json::from_str::<Lanes>(r#"{"prio":[1,16,3]}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid value for field `prio`: value truncated"));
+}
+
+#[test]
+pub fn test_serde_raw() {
+    let value = Raw(0).with_low(0xA).with_high(0x2);
+    assert_eq!(serde_json::to_string(&value).unwrap(), "42");
+    assert_eq!(serde_json::from_str::<Raw>("42").unwrap(), value);
+    assert!(serde_json::from_str::<Raw>("256").is_err());
+}
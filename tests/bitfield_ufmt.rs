@@ -0,0 +1,49 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+use ufmt::uwrite;
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct Permissions(u8);
+    impl uDebug;
+    bool, read, set_read: 0;
+    bool, write, set_write: 1;
+}
+
+bitfield! {
+    pub struct Control(u16);
+    impl uDebug;
+    u8, level, set_level: 7, 0;
+    u8, nested Permissions, permissions, set_permissions: 9, 8;
+    bool, _, set_reset: 15;
+}
+
+bitfield! {
+    pub struct Wide([u16; 2]);
+    impl uDebug;
+    i8, offset, set_offset: 23, 16;
+}
+
+bitfield! {
+    pub struct Lanes(u16);
+    impl uDebug;
+    u8, prio, set_prio: [3] 3, 0;
+    bool, _, set_flag: [2] 12;
+}
+
+#[test]
+pub fn test_udebug() {
+    let mut output = String::new();
+    uwrite!(output, "{:?}", Control(0x022A)).unwrap();
+    assert_eq!(output, "Control { raw: 0x022a, level: 42, permissions: Permissions { raw: 0x02, read: false, write: true } }");
+
+    output.clear();
+    uwrite!(output, "{:?}", Wide([0x0000, 0x00FE])).unwrap();
+    assert_eq!(output, "Wide { raw: 0x00fe0000, offset: -2 }");
+
+    output.clear();
+    uwrite!(output, "{:?}", Lanes(0x1321)).unwrap();
+    assert_eq!(output, "Lanes { raw: 0x1321, prio: [1, 2, 3] }");
+}
@@ -1,4 +1,4 @@
-use const_bitfield::{BitRange, BitRangeMut};
+use const_bitfield::{BitRange, BitRangeMut, BitfieldError};
 use std::panic;
 
 macro_rules! impl_test_range_uint {
@@ -138,6 +138,74 @@ macro_rules! impl_test_range_uint {
                 )*
             }
 
+            #[test]
+            pub fn test_try_bits_valid() {
+                let result: Result<$type, _> = MAX_VALUE.try_bits(MAX_MSB, 0);
+                assert_eq!(result, Ok(MAX_VALUE));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_lsb() {
+                let result = BitRange::<$type>::try_bits(&MAX_VALUE, BIT_LENGTH, BIT_LENGTH);
+                assert_eq!(result, Err(BitfieldError::LsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_msb() {
+                let result = BitRange::<$type>::try_bits(&MAX_VALUE, BIT_LENGTH, 0);
+                assert_eq!(result, Err(BitfieldError::MsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_msb_lsb() {
+                let result = BitRange::<$type>::try_bits(&MAX_VALUE, 0, MAX_MSB);
+                assert_eq!(result, Err(BitfieldError::InvertedRange));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_into_smaller() {
+                $(
+                    let result = BitRange::<$smaller_types>::try_bits(&MAX_VALUE, MAX_MSB, 0);
+                    assert_eq!(result, Err(BitfieldError::ValueTruncated));
+                )*
+            }
+
+            #[test]
+            pub fn test_try_set_bits_valid() {
+                let mut value: $type = 0;
+                assert!(value.try_set_bits(MAX_MSB, 0, MAX_VALUE).is_ok());
+                assert_eq!(value, MAX_VALUE);
+            }
+
+            #[test]
+            pub fn test_try_set_bits_invalid_lsb() {
+                let mut value: $type = 0;
+                let result = value.try_set_bits(BIT_LENGTH, BIT_LENGTH, 0 as $type);
+                assert_eq!(result, Err(BitfieldError::LsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_set_bits_invalid_msb() {
+                let mut value: $type = 0;
+                let result = value.try_set_bits(BIT_LENGTH, 0, 0 as $type);
+                assert_eq!(result, Err(BitfieldError::MsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_set_bits_invalid_msb_lsb() {
+                let mut value: $type = 0;
+                let result = value.try_set_bits(0, MAX_MSB, 0 as $type);
+                assert_eq!(result, Err(BitfieldError::InvertedRange));
+            }
+
+            #[test]
+            pub fn test_try_set_bits_truncated_value() {
+                let mut value: $type = 0;
+                let result = value.try_set_bits(MAX_MSB, 1, MAX_VALUE);
+                assert_eq!(result, Err(BitfieldError::ValueTruncated));
+                assert_eq!(value, 0);
+            }
+
             fn expect_group_of_bits(value: $type, msb: usize, lsb: usize) {
                 // ensure leading bits are clear
                 if lsb > 0 {
@@ -166,3 +234,309 @@ impl_test_range_uint!(range_u16, u16, i16, [u8], [u32, u64, u128]);
 impl_test_range_uint!(range_u32, u32, i32, [u8, u16], [u64, u128]);
 impl_test_range_uint!(range_u64, u64, i64, [u8, u16, u32], [u128]);
 impl_test_range_uint!(range_u128, u128, i128, [u8, u16, u32, u64], []);
+
+macro_rules! impl_test_range_signed {
+    ($name:ident, $type:ty, [$($signed_types:ident),*]) => {
+        mod $name {
+            use super::*;
+
+            const BIT_LENGTH: usize = ::core::mem::size_of::<$type>() * 8;
+            const MAX_MSB: usize = BIT_LENGTH - 1;
+
+            $(
+                mod $signed_types {
+                    use super::*;
+
+                    const SIGNED_BIT_LENGTH: usize = ::core::mem::size_of::<$signed_types>() * 8;
+                    const FULL_BIT_LENGTH: usize = if BIT_LENGTH < SIGNED_BIT_LENGTH {
+                        BIT_LENGTH
+                    } else {
+                        SIGNED_BIT_LENGTH
+                    };
+
+                    #[test]
+                    pub fn test_bits_sign_extend() {
+                        for lsb in [0, BIT_LENGTH - 4] {
+                            let value: $type = 0b1111 << lsb;
+                            let result: $signed_types = value.bits(lsb + 3, lsb);
+                            assert_eq!(result, -1);
+
+                            let value: $type = 0b1000 << lsb;
+                            let result: $signed_types = value.bits(lsb + 3, lsb);
+                            assert_eq!(result, -8);
+
+                            let value: $type = 0b0111 << lsb;
+                            let result: $signed_types = value.bits(lsb + 3, lsb);
+                            assert_eq!(result, 7);
+                        }
+                    }
+
+                    #[test]
+                    pub fn test_bits_sign_extend_single() {
+                        for bit in 0..BIT_LENGTH {
+                            let value: $type = 0b1 << bit;
+                            let result: $signed_types = value.bits(bit, bit);
+                            assert_eq!(result, -1);
+                        }
+                    }
+
+                    #[test]
+                    pub fn test_bits_sign_extend_full() {
+                        let value: $type = 0b1 << (FULL_BIT_LENGTH - 1);
+                        let result: $signed_types = value.bits(FULL_BIT_LENGTH - 1, 0);
+                        assert_eq!(result, <$signed_types>::MIN >> (SIGNED_BIT_LENGTH - FULL_BIT_LENGTH));
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_negative() {
+                        for lsb in [0, BIT_LENGTH - 4] {
+                            let mut value: $type = 0;
+                            value.set_bits(lsb + 3, lsb, -1 as $signed_types);
+                            assert_eq!(value, 0b1111 << lsb);
+
+                            let mut value: $type = 0;
+                            value.set_bits(lsb + 3, lsb, -8 as $signed_types);
+                            assert_eq!(value, 0b1000 << lsb);
+
+                            let mut value: $type = <$type>::MAX;
+                            value.set_bits(lsb + 3, lsb, -8 as $signed_types);
+                            assert_eq!(value, !(0b0111 << lsb));
+                        }
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_roundtrip() {
+                        for field_value in -8..=7 {
+                            let mut value: $type = 0;
+                            value.set_bits(MAX_MSB, MAX_MSB - 3, field_value as $signed_types);
+                            let result: $signed_types = value.bits(MAX_MSB, MAX_MSB - 3);
+                            assert_eq!(result, field_value as $signed_types);
+                        }
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_full() {
+                        let mut value: $type = 0;
+                        let min_value = <$signed_types>::MIN >> (SIGNED_BIT_LENGTH - FULL_BIT_LENGTH);
+                        value.set_bits(FULL_BIT_LENGTH - 1, 0, min_value);
+                        assert_eq!(value, 0b1 << (FULL_BIT_LENGTH - 1));
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_truncated_positive() {
+                        let result = panic::catch_unwind(|| {
+                            let mut value: $type = 0;
+                            value.set_bits(3, 0, 16 as $signed_types);
+                        });
+                        assert!(result.is_err());
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_zero_extended() {
+                        for field_value in 8..=15 {
+                            let mut value: $type = 0;
+                            value.set_bits(3, 0, field_value as $signed_types);
+                            assert_eq!(value, field_value);
+
+                            let result: $signed_types = value.bits(3, 0);
+                            assert_eq!(result, field_value as $signed_types - 16);
+                        }
+                    }
+
+                    #[test]
+                    pub fn test_try_set_bits_truncated_zero_extended() {
+                        let mut value: $type = 0;
+                        let result = value.try_set_bits(3, 0, 16 as $signed_types);
+                        assert_eq!(result, Err(BitfieldError::ValueTruncated));
+                        assert_eq!(value, 0);
+                    }
+
+                    #[test]
+                    pub fn test_set_bits_truncated_negative() {
+                        let result = panic::catch_unwind(|| {
+                            let mut value: $type = 0;
+                            value.set_bits(3, 0, -9 as $signed_types);
+                        });
+                        assert!(result.is_err());
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_test_range_signed!(signed_u8, u8, [i8, i16, i32, i64, i128]);
+impl_test_range_signed!(signed_u16, u16, [i8, i16, i32, i64, i128]);
+impl_test_range_signed!(signed_u32, u32, [i8, i16, i32, i64, i128]);
+impl_test_range_signed!(signed_u64, u64, [i8, i16, i32, i64, i128]);
+impl_test_range_signed!(signed_u128, u128, [i8, i16, i32, i64, i128]);
+
+macro_rules! impl_test_range_array {
+    ($name:ident, $type:ty, $length:expr, [$($range_types:ty),*]) => {
+        mod $name {
+            use super::*;
+
+            const ELEMENT_BIT_LENGTH: usize = ::core::mem::size_of::<$type>() * 8;
+            const BIT_LENGTH: usize = ELEMENT_BIT_LENGTH * $length;
+            const MAX_MSB: usize = BIT_LENGTH - 1;
+            const PATTERN: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+
+            #[test]
+            pub fn test_bits_matches_uint() {
+                let array = to_array(PATTERN);
+                $(
+                    let range_bit_length = ::core::mem::size_of::<$range_types>() * 8;
+                    for lsb in 0..BIT_LENGTH {
+                        for msb in lsb..BIT_LENGTH.min(lsb + range_bit_length) {
+                            let expected: $range_types = PATTERN.bits(msb, lsb);
+                            let actual: $range_types = array.bits(msb, lsb);
+                            assert_eq!(actual, expected, "mismatch for bits {}..={}", lsb, msb);
+                        }
+                    }
+                )*
+            }
+
+            #[test]
+            pub fn test_set_bits_matches_uint() {
+                $(
+                    let range_bit_length = ::core::mem::size_of::<$range_types>() * 8;
+                    for lsb in 0..BIT_LENGTH {
+                        for msb in lsb..BIT_LENGTH.min(lsb + range_bit_length) {
+                            let value: $range_types = (!PATTERN).bits(msb - lsb, 0);
+                            let mut expected = PATTERN;
+                            expected.set_bits(msb, lsb, value);
+                            let mut actual = to_array(PATTERN);
+                            actual.set_bits(msb, lsb, value);
+                            assert_eq!(actual, to_array(expected), "mismatch for bits {}..={}", lsb, msb);
+                        }
+                    }
+                )*
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_lsb() {
+                let result = BitRange::<u8>::try_bits(&to_array(0), BIT_LENGTH, BIT_LENGTH);
+                assert_eq!(result, Err(BitfieldError::LsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_msb() {
+                let result = BitRange::<u8>::try_bits(&to_array(0), BIT_LENGTH, MAX_MSB);
+                assert_eq!(result, Err(BitfieldError::MsbOutOfBounds));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_msb_lsb() {
+                let result = BitRange::<u8>::try_bits(&to_array(0), 0, 1);
+                assert_eq!(result, Err(BitfieldError::InvertedRange));
+            }
+
+            #[test]
+            pub fn test_try_bits_invalid_into_smaller() {
+                let result = BitRange::<u64>::try_bits(&to_array(0), MAX_MSB, 0);
+                assert_eq!(result, Err(BitfieldError::ValueTruncated));
+            }
+
+            #[test]
+            pub fn test_try_set_bits_truncated_value() {
+                let mut array = to_array(0);
+                let result = array.try_set_bits(ELEMENT_BIT_LENGTH + 3, ELEMENT_BIT_LENGTH - 4, 0x100u16);
+                assert_eq!(result, Err(BitfieldError::ValueTruncated));
+                assert_eq!(array, to_array(0));
+            }
+
+            #[test]
+            pub fn test_set_bits_negative_wide() {
+                let mut array = to_array(0);
+                array.set_bits(MAX_MSB, 4, -1i8);
+                assert_eq!(array, to_array(!0 << 4));
+            }
+
+            #[test]
+            pub fn test_try_set_bits_truncated_signed() {
+                let mut array = to_array(0);
+                let result = array.try_set_bits(ELEMENT_BIT_LENGTH + 1, ELEMENT_BIT_LENGTH - 2, 16i8);
+                assert_eq!(result, Err(BitfieldError::ValueTruncated));
+                assert_eq!(array, to_array(0));
+            }
+
+            fn to_array(value: u128) -> [$type; $length] {
+                let mut array = [0; $length];
+                for (index, element) in array.iter_mut().enumerate() {
+                    *element = (value >> (index * ELEMENT_BIT_LENGTH)) as $type;
+                }
+                array
+            }
+        }
+    };
+}
+
+impl_test_range_array!(array_u8, u8, 16, [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]);
+impl_test_range_array!(array_u16, u16, 8, [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]);
+impl_test_range_array!(array_u32, u32, 4, [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]);
+impl_test_range_array!(array_u64, u64, 2, [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]);
+
+mod defaults {
+    use super::*;
+    use const_bitfield::{Bit, BitMut};
+
+    struct Wrapper(u16);
+
+    impl BitRange<u8> for Wrapper {
+        fn bits(&self, msb: usize, lsb: usize) -> u8 {
+            self.0.bits(msb, lsb)
+        }
+    }
+
+    impl BitRangeMut<u8> for Wrapper {
+        fn set_bits(&mut self, msb: usize, lsb: usize, value: u8) -> &mut Self {
+            self.0.set_bits(msb, lsb, value);
+            self
+        }
+    }
+
+    struct Flags(u8);
+
+    impl Bit for Flags {
+        fn bit(&self, bit: usize) -> bool {
+            self.0 >> bit & 1 != 0
+        }
+    }
+
+    impl BitMut for Flags {
+        fn set_bit(&mut self, bit: usize, value: bool) -> &mut Self {
+            self.0 = self.0 & !(1 << bit) | (value as u8) << bit;
+            self
+        }
+    }
+
+    #[test]
+    pub fn test_try_bits_default() {
+        let wrapper = Wrapper(0xABCD);
+        assert_eq!(wrapper.try_bits(11, 4), Ok(0xBC));
+        assert_eq!(wrapper.try_bits(16, 16), Err(BitfieldError::LsbOutOfBounds));
+        assert_eq!(wrapper.try_bits(16, 15), Err(BitfieldError::MsbOutOfBounds));
+        assert_eq!(wrapper.try_bits(4, 5), Err(BitfieldError::InvertedRange));
+        assert_eq!(wrapper.try_bits(15, 0), Err(BitfieldError::ValueTruncated));
+    }
+
+    #[test]
+    pub fn test_try_set_bits_default() {
+        let mut wrapper = Wrapper(0);
+        assert!(wrapper.try_set_bits(11, 4, 0xBC).is_ok());
+        assert_eq!(wrapper.0, 0x0BC0);
+        assert!(matches!(wrapper.try_set_bits(16, 15, 0), Err(BitfieldError::MsbOutOfBounds)));
+        assert!(matches!(wrapper.try_set_bits(4, 5, 0), Err(BitfieldError::InvertedRange)));
+        assert_eq!(wrapper.0, 0x0BC0);
+    }
+
+    #[test]
+    pub fn test_try_bit_default() {
+        let mut flags = Flags(0);
+        assert!(flags.try_set_bit(7, true).is_ok());
+        assert_eq!(flags.try_bit(7), Ok(true));
+        assert_eq!(flags.try_bit(8), Err(BitfieldError::LsbOutOfBounds));
+        assert!(matches!(flags.try_set_bit(8, true), Err(BitfieldError::LsbOutOfBounds)));
+        assert_eq!(flags.0, 0x80);
+    }
+}
@@ -1,6 +1,30 @@
 #[test]
 fn test_ui() {
     let t = trybuild::TestCases::new();
-    t.pass("tests/ui/*-pass-*.rs");
-    t.compile_fail("tests/ui/*-fail-*.rs");
+    t.pass("tests/ui/01-pass-bitfield-struct-visibility.rs");
+    t.pass("tests/ui/02-pass-bitfield-struct-attr.rs");
+    t.pass("tests/ui/03-pass-bitfield-fields-single.rs");
+    t.pass("tests/ui/04-pass-bitfield-fields-getter-setter.rs");
+    t.pass("tests/ui/05-pass-bitfield-fields-visibility.rs");
+    t.compile_fail("tests/ui/06-fail-bitfield-fields-visibility.rs");
+    t.compile_fail("tests/ui/07-fail-bitfield-fields-none.rs");
+    t.pass("tests/ui/08-pass-bitfield-fields-overlap.rs");
+    t.pass("tests/ui/09-pass-bitfield-fields-convert.rs");
+    t.pass("tests/ui/10-pass-bitfield-fields-try-convert.rs");
+    t.compile_fail("tests/ui/11-fail-bitfield-fields-overlap.rs");
+    t.compile_fail("tests/ui/12-fail-bitfield-fields-range.rs");
+    t.compile_fail("tests/ui/13-fail-bitfield-fields-reserved.rs");
+
+    // conversions within default values rely on const trait implementations, which are only
+    // available on Rust nightly
+    #[cfg(not(feature = "stable"))]
+    t.compile_fail("tests/ui/14-fail-bitfield-fields-default.rs");
+    #[cfg(feature = "stable")]
+    t.compile_fail("tests/ui/14-fail-bitfield-fields-default-stable.rs");
+
+    t.compile_fail("tests/ui/15-fail-bitfield-atomic.rs");
+    t.pass("tests/ui/16-pass-bitfield-fields-default.rs");
+    t.compile_fail("tests/ui/17-fail-bitfield-fields-nested.rs");
+    t.pass("tests/ui/18-pass-bitfield-struct-repr.rs");
+    t.compile_fail("tests/ui/19-fail-bitfield-atomic-by-name.rs");
 }
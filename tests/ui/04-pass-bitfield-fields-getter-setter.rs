@@ -1,5 +1,5 @@
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
 use const_bitfield::bitfield;
 
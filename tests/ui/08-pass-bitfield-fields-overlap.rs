@@ -1,15 +1,23 @@
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
 use const_bitfield::bitfield;
 
 bitfield! {
     struct Test(u8);
-    bool, b0, set_b0: 0;
-    bool, b6, set_b6: 6;
+    overlap bool, b0, set_b0: 0;
+    pub overlap bool, b6, set_b6: 6;
 
-    u8, b0_3, set_b0_3: 3, 0;
-    u8, b4_7, set_b4_7: 7, 4;
+    overlap u8, b0_3, set_b0_3: 3, 0;
+    pub overlap u8, b4_7, set_b4_7: 7, 4;
+}
+
+bitfield! {
+    struct Partial(u8);
+    overlap u8, low, set_low: 3, 0;
+    overlap u8, lowest, set_lowest: 1, 0;
+    bool, flag, _: 4;
+    u8, _, set_high: 7, 5;
 }
 
 pub fn main() {}
@@ -1,9 +1,20 @@
-#![feature(const_convert)]
-#![feature(const_mut_refs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
 
 use const_bitfield::bitfield;
 
+// const trait implementation on Rust nightly, regular trait implementation with `stable` feature
+#[cfg(not(feature = "stable"))]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl const $($impl)* };
+}
+
+#[cfg(feature = "stable")]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl $($impl)* };
+}
+
 bitfield! {
     struct ConvertBit(u8);
     bool, bit_raw, set_bit_raw: 0;
@@ -77,7 +88,7 @@ enum NumField1 {
     D = 0b11,
 }
 
-impl const From<u8> for NumField1 {
+impl_convert! {From<u8> for NumField1 {
     fn from(value: u8) -> Self {
         match value {
             0b00 => NumField1::A,
@@ -87,9 +98,9 @@ impl const From<u8> for NumField1 {
             _ => panic!("invalid value"),
         }
     }
-}
+}}
 
-impl const From<NumField1> for u8 {
+impl_convert! {From<NumField1> for u8 {
     fn from(value: NumField1) -> Self {
         match value {
             NumField1::A => 0b00,
@@ -98,7 +109,7 @@ impl const From<NumField1> for u8 {
             NumField1::D => 0b11,
         }
     }
-}
+}}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum NumField2 {
@@ -108,7 +119,7 @@ enum NumField2 {
     Z = 0b11,
 }
 
-impl const From<u8> for NumField2 {
+impl_convert! {From<u8> for NumField2 {
     fn from(value: u8) -> Self {
         match value {
             0b00 => NumField2::W,
@@ -118,9 +129,9 @@ impl const From<u8> for NumField2 {
             _ => panic!("invalid value"),
         }
     }
-}
+}}
 
-impl const From<NumField2> for u8 {
+impl_convert! {From<NumField2> for u8 {
     fn from(value: NumField2) -> Self {
         match value {
             NumField2::W => 0b00,
@@ -129,7 +140,7 @@ impl const From<NumField2> for u8 {
             NumField2::Z => 0b11,
         }
     }
-}
+}}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum YesNoField {
@@ -137,23 +148,23 @@ enum YesNoField {
     No,
 }
 
-impl const From<bool> for YesNoField {
+impl_convert! {From<bool> for YesNoField {
     fn from(value: bool) -> Self {
         match value {
             false => YesNoField::No,
             true => YesNoField::Yes,
         }
     }
-}
+}}
 
-impl const From<YesNoField> for bool {
+impl_convert! {From<YesNoField> for bool {
     fn from(value: YesNoField) -> Self {
         match value {
             YesNoField::No => false,
             YesNoField::Yes => true,
         }
     }
-}
+}}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum TrueFalseField {
@@ -161,20 +172,20 @@ enum TrueFalseField {
     False,
 }
 
-impl const From<bool> for TrueFalseField {
+impl_convert! {From<bool> for TrueFalseField {
     fn from(value: bool) -> Self {
         match value {
             false => TrueFalseField::False,
             true => TrueFalseField::True,
         }
     }
-}
+}}
 
-impl const From<TrueFalseField> for bool {
+impl_convert! {From<TrueFalseField> for bool {
     fn from(value: TrueFalseField) -> Self {
         match value {
             TrueFalseField::False => false,
             TrueFalseField::True => true,
         }
     }
-}
+}}
@@ -0,0 +1,208 @@
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::{bitfield, BitfieldError};
+
+// const trait implementation on Rust nightly, regular trait implementation with `stable` feature
+#[cfg(not(feature = "stable"))]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl const $($impl)* };
+}
+
+#[cfg(feature = "stable")]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl $($impl)* };
+}
+
+bitfield! {
+    struct TryConvertBit(u8);
+    bool, try_into Flag, bit_into, set_bit_into: 0;
+    bool, try_from Flag, bit_from, set_bit_from: 1;
+    bool, try_from try_into Flag, bit_both, set_bit_both: 2;
+}
+
+bitfield! {
+    struct TryConvertBits(u16);
+    u8, try_into Partial, bits_into, set_bits_into: 1, 0;
+    u8, try_from Partial, bits_from, set_bits_from: 3, 2;
+    u8, try_from try_into Partial, bits_both, set_bits_both: 5, 4;
+    u8, try_from Wide, try_into Partial, bits_mixed_1, set_bits_mixed_1: 7, 6;
+    u8, from Small, try_into Partial, bits_mixed_2, set_bits_mixed_2: 9, 8;
+    u8, try_from Wide, into u16, bits_mixed_3, set_bits_mixed_3: 11, 10;
+}
+
+// conversions within const contexts and default values require Rust nightly
+#[cfg(not(feature = "stable"))]
+bitfield! {
+    struct TryConvertDefault(u8);
+    u8, try_from Wide, into u16, value, set_value: 3, 0 = Wide(0x5);
+    bool, try_from Flag, flag, set_flag: 7 = Flag::Off;
+}
+
+#[cfg(not(feature = "stable"))]
+const CONST_BITS: TryConvertBits = {
+    let mut bf = TryConvertBits(0);
+    match bf.set_bits_both(Partial::C) {
+        Ok(_) => bf,
+        Err(_) => panic!("conversion failed"),
+    }
+};
+
+pub fn main() {
+    test_try_convert_bit();
+    test_try_convert_bits();
+    #[cfg(not(feature = "stable"))]
+    {
+        test_try_convert_const();
+        test_try_convert_default();
+    }
+}
+
+fn test_try_convert_bit() {
+    // test try_into conversions of single bit
+    let bf = TryConvertBit(0b101);
+    let result: Result<Flag, InvalidFlag> = bf.bit_into();
+    assert_eq!(result, Err(InvalidFlag));
+    let _result: bool = bf.bit_from();
+    assert_eq!(bf.bit_both(), Err(InvalidFlag));
+
+    // test try_from conversions of single bit
+    let mut bf = TryConvertBit(0);
+    bf.set_bit_into(false);
+    assert!(bf.set_bit_from(Flag::Off).is_ok());
+    assert_eq!(bf.set_bit_both(Flag::On).err(), Some(InvalidFlag));
+    assert_eq!(bf.try_set_bit_both(Flag::On).err(), Some(BitfieldError::InvalidValue));
+    assert_eq!(bf.0, 0);
+}
+
+fn test_try_convert_bits() {
+    // test try_into conversions of bit range
+    let bf = TryConvertBits(0b00_11_10_01_00_11);
+    assert_eq!(bf.bits_into(), Err(InvalidPartial(0b11)));
+    let _result: u8 = bf.bits_from();
+    assert_eq!(bf.bits_both(), Ok(Partial::B));
+    assert_eq!(bf.bits_mixed_1(), Ok(Partial::C));
+    assert_eq!(bf.bits_mixed_2(), Err(InvalidPartial(0b11)));
+    let _result: u16 = bf.bits_mixed_3();
+
+    // test try_from conversions of bit range
+    let mut bf = TryConvertBits(0);
+    bf.set_bits_into(0b11);
+    assert!(bf.set_bits_from(Partial::B).is_ok());
+    assert!(bf.set_bits_both(Partial::C).is_ok());
+    assert!(bf.set_bits_mixed_1(Wide(0b10)).is_ok());
+    assert_eq!(bf.set_bits_mixed_1(Wide(0x100)).err(), Some(InvalidWide));
+    bf.set_bits_mixed_2(Small(0b00));
+    assert_eq!(bf.try_set_bits_mixed_3(Wide(0x100)).err(), Some(BitfieldError::InvalidValue));
+    assert_eq!(bf.try_set_bits_mixed_3(Wide(0x10)).err(), Some(BitfieldError::ValueTruncated));
+    assert_eq!(bf.0, 0b00_10_10_01_11);
+
+    // test fallible with_ builders of bit range
+    let bf = TryConvertBits(0).with_bits_both(Partial::C);
+    assert_eq!(bf.map(|bf| bf.bits_both()), Ok(Ok(Partial::C)));
+    let bf = TryConvertBits(0).with_bits_mixed_1(Wide(0x100));
+    assert_eq!(bf.err(), Some(InvalidWide));
+}
+
+#[cfg(not(feature = "stable"))]
+fn test_try_convert_const() {
+    assert_eq!(CONST_BITS.bits_both(), Ok(Partial::C));
+}
+
+#[cfg(not(feature = "stable"))]
+fn test_try_convert_default() {
+    let mut bf = TryConvertDefault::new();
+    assert_eq!(bf.0, 0b0000_0101);
+    bf.set_value(Wide(0xA)).unwrap().set_flag(Flag::Off).unwrap();
+    bf.reset_value();
+    assert_eq!(bf.0, 0b0000_0101);
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Flag {
+    Off,
+    On,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct InvalidFlag;
+
+impl_convert! {TryFrom<bool> for Flag {
+    type Error = InvalidFlag;
+
+    fn try_from(value: bool) -> Result<Self, Self::Error> {
+        match value {
+            false => Ok(Flag::Off),
+            true => Err(InvalidFlag),
+        }
+    }
+}}
+
+impl_convert! {TryFrom<Flag> for bool {
+    type Error = InvalidFlag;
+
+    fn try_from(value: Flag) -> Result<Self, Self::Error> {
+        match value {
+            Flag::Off => Ok(false),
+            Flag::On => Err(InvalidFlag),
+        }
+    }
+}}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Partial {
+    A = 0b00,
+    B = 0b01,
+    C = 0b10,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct InvalidPartial(u8);
+
+impl_convert! {TryFrom<u8> for Partial {
+    type Error = InvalidPartial;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(Partial::A),
+            0b01 => Ok(Partial::B),
+            0b10 => Ok(Partial::C),
+            _ => Err(InvalidPartial(value)),
+        }
+    }
+}}
+
+impl_convert! {TryFrom<Partial> for u8 {
+    type Error = InvalidPartial;
+
+    fn try_from(value: Partial) -> Result<Self, Self::Error> {
+        Ok(value as u8)
+    }
+}}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Small(u8);
+
+impl_convert! {From<Small> for u8 {
+    fn from(value: Small) -> Self {
+        value.0
+    }
+}}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Wide(u32);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct InvalidWide;
+
+impl_convert! {TryFrom<Wide> for u8 {
+    type Error = InvalidWide;
+
+    fn try_from(value: Wide) -> Result<Self, Self::Error> {
+        match value.0 {
+            0..=0xFF => Ok(value.0 as u8),
+            _ => Err(InvalidWide),
+        }
+    }
+}}
@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    struct Unmarked(u16);
+    u8, low, set_low: 7, 0;
+    u16, high, set_high: 15, 7;
+}
+
+bitfield! {
+    struct PartiallyMarked(u8);
+    overlap u8, low, set_low: 3, 0;
+    bool, _, set_flag: 2;
+}
+
+bitfield! {
+    struct ArrayStride(u16);
+    u8, prio, set_prio: [4] 3, 0 stride 3;
+}
+
+bitfield! {
+    struct ArrayField(u16);
+    bool, flag, set_flag: [8] 0;
+    u16, high, set_high: 15, 7;
+}
+
+pub fn main() {}
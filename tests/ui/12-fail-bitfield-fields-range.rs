@@ -0,0 +1,33 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    struct OutOfBounds(u8);
+    u8, foo, set_foo: 9, 2;
+    bool, _, set_bar: 10;
+}
+
+bitfield! {
+    struct Inverted(u16);
+    u8, foo, set_foo: 0, 7;
+}
+
+bitfield! {
+    #[bit_order(msb0)]
+    struct InvertedMsb0(u16);
+    u8, foo, set_foo: 7, 0;
+}
+
+bitfield! {
+    struct TooWide(u32);
+    u8, foo, set_foo: 8, 0;
+}
+
+bitfield! {
+    struct ArrayOutOfBounds(u16);
+    u8, prio, set_prio: [5] 3, 0;
+}
+
+pub fn main() {}
@@ -0,0 +1,22 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    struct Overlapping(u8);
+    u8, low, set_low: 3, 0;
+    reserved 0: 3;
+}
+
+bitfield! {
+    struct InvalidValue(u8);
+    reserved 2: 7, 4;
+}
+
+bitfield! {
+    struct OutOfBounds(u8);
+    reserved 1: 8;
+}
+
+pub fn main() {}
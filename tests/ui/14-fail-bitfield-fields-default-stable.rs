@@ -0,0 +1,17 @@
+use const_bitfield::bitfield;
+
+// conversions can not be used within default values on Rust stable
+bitfield! {
+    struct Convert(u8);
+    u8, try_from u16, high, set_high: 7, 4 = 0x1;
+}
+
+bitfield! {
+    struct ReadOnly(u8);
+    u8, version, _: 3, 0 = 0x4;
+}
+
+pub fn main() {
+    let mut value = ReadOnly::new();
+    value.reset_version();
+}
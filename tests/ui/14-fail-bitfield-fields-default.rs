@@ -0,0 +1,20 @@
+#![feature(const_convert)]
+#![feature(const_mut_refs)]
+#![feature(const_trait_impl)]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    struct Invalid(u8);
+    u8, try_from u16, high, set_high: 7, 4 = 0x100;
+}
+
+bitfield! {
+    struct ReadOnly(u8);
+    u8, version, _: 3, 0 = 0x4;
+}
+
+pub fn main() {
+    let mut value = ReadOnly::new();
+    value.reset_version();
+}
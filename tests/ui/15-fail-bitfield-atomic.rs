@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    struct AtomicDebug(AtomicU8);
+    impl Debug;
+    bool, flag, set_flag: 0;
+}
+
+bitfield! {
+    struct AtomicReserved(AtomicU8);
+    bool, flag, set_flag: 0;
+    reserved 0: 7, 1;
+}
+
+bitfield! {
+    struct AtomicArray(AtomicU8);
+    bool, flag, set_flag: [8] 0;
+}
+
+bitfield! {
+    struct AtomicDefault(AtomicU8);
+    bool, flag, set_flag: 0 = true;
+}
+
+bitfield! {
+    struct AtomicOverlap(AtomicU8);
+    u8, low, set_low: 3, 0;
+    bool, flag, set_flag: 2;
+}
+
+fn main() {}
@@ -0,0 +1,121 @@
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+// const trait implementation on Rust nightly, regular trait implementation with `stable` feature
+#[cfg(not(feature = "stable"))]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl const $($impl)* };
+}
+
+#[cfg(feature = "stable")]
+macro_rules! impl_convert {
+    ($($impl:tt)*) => { impl $($impl)* };
+}
+
+const BASE: usize = 4;
+
+bitfield! {
+    struct ExprDefault(u16);
+    u8, low, set_low: BASE - 1, 0 = 0x5;
+    u8, high, set_high: 15, BASE * 3 = 0xA;
+    bool, flag, set_flag: BASE + 1 = true;
+    u8, middle, set_middle: BASE * 2 + 1, BASE + 2;
+}
+
+// conversions within const contexts and default values require Rust nightly
+#[cfg(not(feature = "stable"))]
+bitfield! {
+    struct ConvertDefault(u8);
+    u8, from into NumField, bits, set_bits: 1, 0 = NumField::C;
+    bool, from into YesNoField, bit, set_bit: 2 = YesNoField::Yes;
+}
+
+const EXPR_DEFAULT: ExprDefault = ExprDefault::new();
+#[cfg(not(feature = "stable"))]
+const CONVERT_DEFAULT: ConvertDefault = ConvertDefault::new();
+
+pub fn main() {
+    test_expr_default();
+    #[cfg(not(feature = "stable"))]
+    test_convert_default();
+}
+
+fn test_expr_default() {
+    assert_eq!(EXPR_DEFAULT.0, 0xA025);
+
+    let mut bf = ExprDefault(0);
+    bf.set_low(0x2).set_flag(false).set_middle(0xF);
+    bf.reset_low().reset_flag();
+    assert_eq!(bf.low(), 0x5);
+    assert!(bf.flag());
+    assert_eq!(bf.middle(), 0xF);
+}
+
+#[cfg(not(feature = "stable"))]
+fn test_convert_default() {
+    assert_eq!(CONVERT_DEFAULT.0, 0b110);
+
+    let mut bf = ConvertDefault(0);
+    bf.set_bits(NumField::A).set_bit(YesNoField::No);
+    bf.reset_bits().reset_bit();
+    assert_eq!(bf.bits(), NumField::C);
+    assert_eq!(bf.bit(), YesNoField::Yes);
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum NumField {
+    A = 0b00,
+    B = 0b01,
+    C = 0b10,
+    D = 0b11,
+}
+
+impl_convert! {From<u8> for NumField {
+    fn from(value: u8) -> Self {
+        match value {
+            0b00 => NumField::A,
+            0b01 => NumField::B,
+            0b10 => NumField::C,
+            0b11 => NumField::D,
+            _ => panic!("invalid value"),
+        }
+    }
+}}
+
+impl_convert! {From<NumField> for u8 {
+    fn from(value: NumField) -> Self {
+        match value {
+            NumField::A => 0b00,
+            NumField::B => 0b01,
+            NumField::C => 0b10,
+            NumField::D => 0b11,
+        }
+    }
+}}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum YesNoField {
+    Yes,
+    No,
+}
+
+impl_convert! {From<bool> for YesNoField {
+    fn from(value: bool) -> Self {
+        match value {
+            false => YesNoField::No,
+            true => YesNoField::Yes,
+        }
+    }
+}}
+
+impl_convert! {From<YesNoField> for bool {
+    fn from(value: YesNoField) -> Self {
+        match value {
+            YesNoField::No => false,
+            YesNoField::Yes => true,
+        }
+    }
+}}
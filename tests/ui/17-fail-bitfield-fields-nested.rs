@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    struct Perm(u8);
+    bool, read, set_read: 0;
+    bool, exec, set_exec: 5;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    struct Lane(u8);
+    u8, prio, set_prio: [2] 1, 0 stride 4;
+}
+
+bitfield! {
+    struct Entry(u8);
+    u8, nested Perm, perm, set_perm: 2, 1;
+}
+
+bitfield! {
+    struct Lanes(u16);
+    u8, nested Lane, lane, set_lane: [2] 3, 0 stride 8;
+}
+
+fn main() {}
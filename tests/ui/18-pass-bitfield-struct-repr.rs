@@ -0,0 +1,42 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+use core::mem::{align_of, size_of};
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    #[repr(C)]
+    pub struct Packet(u32);
+    u8, kind, set_kind: 7, 0;
+    u16, length, set_length: 23, 8;
+}
+
+bitfield! {
+    #[repr(C, align(8))]
+    #[bit_order(msb0)]
+    pub struct Aligned(u16);
+    u8, high, set_high: 0, 7;
+}
+
+bitfield! {
+    #[repr(C)]
+    pub struct Flags(AtomicU8);
+    bool, ready, set_ready: 0;
+}
+
+fn main() {
+    let mut packet = Packet(0);
+    packet.set_kind(0x2).set_length(0x40);
+    assert_eq!(packet.0, 0x4002);
+    assert_eq!(size_of::<Packet>(), size_of::<u32>());
+
+    let mut aligned = Aligned(0);
+    aligned.set_high(0xAB);
+    assert_eq!(aligned.0, 0xAB00);
+    assert_eq!(align_of::<Aligned>(), 8);
+
+    let flags = Flags(core::sync::atomic::AtomicU8::new(0));
+    flags.set_ready(true, core::sync::atomic::Ordering::Relaxed);
+    assert!(flags.ready(core::sync::atomic::Ordering::Relaxed));
+}
@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "stable"), feature(const_mut_refs))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+
+use const_bitfield::bitfield;
+use core::sync::atomic::AtomicU8;
+
+bitfield! {
+    struct AtomicFlags(AtomicU8);
+    bool, flag, set_flag: 0;
+    u8, count, set_count: 7, 4;
+}
+
+fn main() {
+    // field metadata is available, but no raw access by name
+    assert_eq!(AtomicFlags::FIELDS.len(), 2);
+
+    let flags = AtomicFlags(AtomicU8::new(0));
+    let _ = flags.get_by_name("count");
+    let _ = flags.set_by_name("flag", 1);
+}